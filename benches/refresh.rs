@@ -0,0 +1,98 @@
+//! Compares `Manager::refresh` for power supplies with and without the `uevent` file.
+//!
+//! Attribute files which are not reported in the `uevent` are not read by the refresh,
+//! they are read only when the raw properties are requested.
+//!
+//! Run with `cargo bench --bench refresh`.
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::fs;
+    use std::path::Path;
+
+    use criterion::{criterion_group, Criterion};
+    use starship_battery::{Battery, Manager};
+
+    static ATTRIBUTES: &[(&str, &str)] = &[
+        ("type", "Battery"),
+        ("status", "Discharging"),
+        ("present", "1"),
+        ("technology", "Li-poly"),
+        ("cycle_count", "113"),
+        ("voltage_min_design", "15440000"),
+        ("voltage_now", "16584000"),
+        ("power_now", "7280000"),
+        ("energy_full_design", "57000000"),
+        ("energy_full", "51360000"),
+        ("energy_now", "40270000"),
+        ("capacity", "78"),
+        ("capacity_level", "Normal"),
+        ("temp", "312"),
+        ("model_name", "5B10W13930"),
+        ("manufacturer", "SMP"),
+        ("serial_number", "1234"),
+    ];
+
+    // Not reported in the `uevent` by the kernel
+    static EXTRA_ATTRIBUTES: &[(&str, &str)] = &[
+        ("charge_control_start_threshold", "75"),
+        ("charge_control_end_threshold", "80"),
+        ("charge_behaviour", "[auto] inhibit-charge force-discharge"),
+        ("alarm", "2840000"),
+        ("energy_full_design_min", "57000000"),
+    ];
+
+    fn fixture(root: &Path, with_uevent: bool) {
+        let device = root.join("BAT0");
+        fs::create_dir(&device).unwrap();
+        for (name, value) in ATTRIBUTES.iter().chain(EXTRA_ATTRIBUTES) {
+            fs::write(device.join(name), format!("{}\n", value)).unwrap();
+        }
+        if with_uevent {
+            let uevent = ATTRIBUTES
+                .iter()
+                .map(|(name, value)| format!("POWER_SUPPLY_{}={}\n", name.to_uppercase(), value))
+                .collect::<String>();
+            fs::write(device.join("uevent"), uevent).unwrap();
+        }
+    }
+
+    fn bench_refresh(c: &mut Criterion, name: &str, with_uevent: bool) {
+        let root = tempfile::tempdir().unwrap();
+        fixture(root.path(), with_uevent);
+
+        let manager = Manager::with_root(root.path()).unwrap();
+        let mut battery: Battery = manager.batteries().unwrap().next().unwrap().unwrap();
+
+        c.bench_function(name, |b| b.iter(|| manager.refresh(&mut battery).unwrap()));
+    }
+
+    fn bench_raw_properties(c: &mut Criterion) {
+        let root = tempfile::tempdir().unwrap();
+        fixture(root.path(), true);
+
+        let manager = Manager::with_root(root.path()).unwrap();
+        let mut battery: Battery = manager.batteries().unwrap().next().unwrap().unwrap();
+
+        c.bench_function("refresh with raw properties", |b| {
+            b.iter(|| {
+                manager.refresh(&mut battery).unwrap();
+                battery.raw_properties().len()
+            })
+        });
+    }
+
+    fn refresh(c: &mut Criterion) {
+        bench_refresh(c, "refresh with uevent", true);
+        bench_refresh(c, "refresh with separate files", false);
+        bench_raw_properties(c);
+    }
+
+    criterion_group!(benches, refresh);
+}
+
+#[cfg(target_os = "linux")]
+criterion::criterion_main!(linux::benches);
+
+#[cfg(not(target_os = "linux"))]
+fn main() {}
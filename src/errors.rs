@@ -42,6 +42,22 @@ impl Error {
         }
     }
 
+    /// Error for the device, which is not available anymore, such as the unplugged battery.
+    ///
+    /// Kind of this error is `ErrorKind::NotFound`, but unlike the other `NotFound` errors,
+    /// such as the missing device attributes, it can be told apart with the
+    /// [Error::is_device_removed](#method.is_device_removed) method.
+    #[allow(unused)]
+    pub(crate) fn device_removed<T>(description: T) -> Error
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Error {
+            source: io::Error::new(io::ErrorKind::NotFound, DeviceRemoved),
+            description: Some(description.into()),
+        }
+    }
+
     #[allow(unused)]
     pub(crate) fn invalid_data<T>(description: T) -> Error
     where
@@ -52,6 +68,55 @@ impl Error {
             description: Some(description.into()),
         }
     }
+
+    #[allow(unused)]
+    pub(crate) fn invalid_input<T>(description: T) -> Error
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Error {
+            source: io::Error::from(io::ErrorKind::InvalidInput),
+            description: Some(description.into()),
+        }
+    }
+
+    #[allow(unused)]
+    pub(crate) fn unsupported<T>(description: T) -> Error
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Error {
+            source: io::Error::from(io::ErrorKind::Unsupported),
+            description: Some(description.into()),
+        }
+    }
+
+    /// Returns the corresponding `::std::io::ErrorKind` for this error.
+    ///
+    /// For example, attempt to change battery settings without root privileges
+    /// results in the `ErrorKind::PermissionDenied` error.
+    pub fn kind(&self) -> io::ErrorKind {
+        self.source.kind()
+    }
+
+    /// Returns `true` if the error was caused by the device, which is not available anymore.
+    pub(crate) fn is_device_removed(&self) -> bool {
+        self.source
+            .get_ref()
+            .is_some_and(|inner| inner.is::<DeviceRemoved>())
+    }
+}
+
+/// Marker for the [Error::device_removed](struct.Error.html#method.device_removed) errors.
+#[derive(Debug)]
+struct DeviceRemoved;
+
+impl StdError for DeviceRemoved {}
+
+impl fmt::Display for DeviceRemoved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Device is not available anymore")
+    }
 }
 
 impl StdError for Error {
@@ -109,3 +174,40 @@ mod plist_impl {
         }
     }
 }
+
+#[cfg(all(target_os = "linux", feature = "upower"))]
+mod zbus_impl {
+    use std::io;
+
+    use super::Error;
+
+    impl From<zbus::Error> for Error {
+        fn from(e: zbus::Error) -> Self {
+            match e {
+                zbus::Error::InputOutput(ref inner) => {
+                    Error::new(io::Error::from(inner.kind()), e.to_string())
+                }
+                zbus::Error::FDO(inner) => Error::from(*inner),
+                // Converted into the well-known `org.freedesktop.DBus.Error.*` errors if possible
+                zbus::Error::MethodError(..) => Error::from(zbus::fdo::Error::from(e)),
+                _ => Error::new(io::Error::from(io::ErrorKind::Other), e.to_string()),
+            }
+        }
+    }
+
+    impl From<zbus::fdo::Error> for Error {
+        fn from(e: zbus::fdo::Error) -> Self {
+            let kind = match &e {
+                // Device object is gone, while the service itself is still available
+                zbus::fdo::Error::UnknownObject(_) => return Error::device_removed(e.to_string()),
+                zbus::fdo::Error::ServiceUnknown(_) | zbus::fdo::Error::NameHasNoOwner(_) => {
+                    io::ErrorKind::NotFound
+                }
+                zbus::fdo::Error::AccessDenied(_) => io::ErrorKind::PermissionDenied,
+                _ => io::ErrorKind::Other,
+            };
+
+            Error::new(io::Error::from(kind), e.to_string())
+        }
+    }
+}
@@ -43,4 +43,14 @@ pub mod errors;
 mod platform;
 
 pub use self::errors::{Error, Result};
-pub use self::types::{Batteries, Battery, Manager, State, Technology};
+#[cfg(all(target_os = "linux", feature = "upower"))]
+pub use self::types::Backend;
+pub use self::types::{
+    Adapter, AdapterType, Adapters, Batteries, Battery, BatteryId, BatterySnapshot, CapacityLevel,
+    ChargeBehaviour, ChargeThresholds, ChargeType, CompositeBattery, DataProvenance,
+    DesignVoltageSource, DeviceKind, Estimator, Health, Identity, Manager, ManagerBuilder,
+    ManufactureDate, PowerFlow, Provenance, RefreshSummary, SanitizePolicy, Scope, State,
+    Technology, UsbType,
+};
+#[cfg(target_os = "linux")]
+pub use self::types::{BatteryEvent, Monitor, UEventSource};
@@ -10,6 +10,7 @@ use std::boxed::Box;
 use std::fmt;
 use std::str;
 
+use super::iokit::IOPM_SERVICE_NAME;
 use super::traits::DataSource;
 use crate::platform::traits::BatteryDevice;
 use crate::types::{State, Technology};
@@ -85,6 +86,11 @@ impl BatteryDevice for IoKitDevice {
         self.source.serial_number()
     }
 
+    fn name(&self) -> String {
+        // There is no device name, sticking to the IOKit service one
+        IOPM_SERVICE_NAME.to_string_lossy().into_owned()
+    }
+
     fn technology(&self) -> Technology {
         Technology::Unknown
     }
@@ -2,9 +2,11 @@ use std::convert::AsRef;
 use std::fmt;
 
 use super::acpi;
-use crate::platform::traits::BatteryDevice;
-use crate::units::{ElectricPotential, Energy, Power, ThermodynamicTemperature};
-use crate::{Result, State, Technology};
+use crate::platform::traits::{missing_values_provenance, BatteryDevice};
+use crate::units::{
+    ElectricCharge, ElectricCurrent, ElectricPotential, Energy, Power, ThermodynamicTemperature,
+};
+use crate::{DataProvenance, DesignVoltageSource, Identity, Provenance, Result, State, Technology};
 
 #[derive(Default)]
 pub struct IoCtlDevice {
@@ -19,9 +21,18 @@ pub struct IoCtlDevice {
     max_capacity: Energy,
     current_capacity: Energy,
 
+    // Native values for batteries reporting in mA and mAh
+    current: Option<ElectricCurrent>,
+    charge: Option<ElectricCharge>,
+    charge_full: Option<ElectricCharge>,
+    charge_full_design: Option<ElectricCharge>,
+    design_voltage_source: Option<DesignVoltageSource>,
+    provenance: DataProvenance,
+
     manufacturer: Option<String>,
     model: Option<String>,
     serial_number: Option<String>,
+    battery_type: Option<String>,
 }
 
 impl IoCtlDevice {
@@ -34,6 +45,7 @@ impl IoCtlDevice {
         device.manufacturer = bif.oem();
         device.model = bif.model();
         device.serial_number = bif.serial();
+        device.battery_type = bif.type_();
         device.technology = bif.technology();
 
         device.refresh(bif, bst).expect("unreachable");
@@ -70,6 +82,20 @@ impl IoCtlDevice {
                 milliampere_hour!(bif.last_full_capacity()) * design_voltage
             }
         };
+        if let acpi::Units::MilliAmperes = bif.units() {
+            self.current = Some(milliampere!(bst.rate()));
+            self.charge = Some(milliampere_hour!(bst.capacity()));
+            self.charge_full = Some(milliampere_hour!(bif.last_full_capacity()));
+            self.charge_full_design = Some(milliampere_hour!(bif.design_capacity()));
+            self.design_voltage_source = Some(DesignVoltageSource::Design);
+            self.provenance = DataProvenance {
+                energy: Provenance::DerivedFromCharge,
+                energy_full: Provenance::DerivedFromCharge,
+                energy_full_design: Provenance::DerivedFromCharge,
+                energy_rate: Provenance::DerivedFromCharge,
+                ..DataProvenance::default()
+            };
+        }
         self.state = bst.state();
         self.voltage = voltage;
 
@@ -118,6 +144,21 @@ impl BatteryDevice for IoCtlDevice {
         self.serial_number.as_ref().map(AsRef::as_ref)
     }
 
+    fn name(&self) -> String {
+        // Same as the `acpiconf -i <unit>` argument
+        self.unit.to_string()
+    }
+
+    fn identity(&self) -> Identity {
+        Identity {
+            vendor: self.manufacturer.clone(),
+            model: self.model.clone(),
+            serial_number: self.serial_number.clone(),
+            battery_type: self.battery_type.clone(),
+            ..Identity::default()
+        }
+    }
+
     fn technology(&self) -> Technology {
         self.technology
     }
@@ -125,6 +166,41 @@ impl BatteryDevice for IoCtlDevice {
     fn cycle_count(&self) -> Option<u32> {
         None
     }
+
+    fn current(&self) -> Option<ElectricCurrent> {
+        self.current
+    }
+
+    fn charge(&self) -> Option<ElectricCharge> {
+        self.charge
+    }
+
+    fn charge_full(&self) -> Option<ElectricCharge> {
+        self.charge_full
+    }
+
+    fn charge_full_design(&self) -> Option<ElectricCharge> {
+        self.charge_full_design
+    }
+
+    fn design_voltage_source(&self) -> Option<DesignVoltageSource> {
+        self.design_voltage_source
+    }
+
+    fn provenance(&self) -> DataProvenance {
+        // Values calculated from the missing charge ones are missing too
+        let missing = missing_values_provenance(self);
+        DataProvenance {
+            energy: self.provenance.energy.combine(missing.energy),
+            energy_full: self.provenance.energy_full.combine(missing.energy_full),
+            energy_full_design: self
+                .provenance
+                .energy_full_design
+                .combine(missing.energy_full_design),
+            energy_rate: self.provenance.energy_rate.combine(missing.energy_rate),
+            ..missing
+        }
+    }
 }
 
 impl fmt::Debug for IoCtlDevice {
@@ -0,0 +1,75 @@
+use std::fmt;
+use std::fs::{self, ReadDir};
+use std::path::Path;
+
+use super::sysfs::{fs as sysfs, Attributes};
+use crate::{Adapter, AdapterType, Result};
+
+pub struct SysFsAdapterIterator {
+    entries: ReadDir,
+}
+
+impl SysFsAdapterIterator {
+    pub fn new<T: AsRef<Path>>(root: T) -> Result<Self> {
+        let entries = fs::read_dir(root)?;
+
+        Ok(SysFsAdapterIterator { entries })
+    }
+
+    fn try_from(path: &Path, type_: AdapterType) -> Result<Adapter> {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let attrs = Attributes::new(path);
+        let online = attrs.get::<u8>("online")?.map(|value| value != 0);
+
+        Ok(Adapter {
+            name,
+            type_,
+            online,
+            usb_type: attrs.selected("usb_type")?,
+            voltage_max: attrs.voltage("voltage_max")?,
+            current_max: attrs.current("current_max")?,
+            input_current_limit: attrs.current("input_current_limit")?,
+        })
+    }
+}
+
+impl Iterator for SysFsAdapterIterator {
+    type Item = Result<Adapter>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            return match self.entries.next() {
+                None => None,
+                // Unable to access sysfs for some reasons
+                Some(Err(e)) => Some(Err(e.into())),
+                Some(Ok(entry)) => {
+                    let path = entry.path();
+                    match sysfs::type_(path.join("type")) {
+                        Ok(type_) => match type_.adapter_type() {
+                            Some(type_) => Some(Self::try_from(&path, type_)),
+                            None => continue,
+                        },
+                        Err(e) => Some(Err(e)),
+                    }
+                }
+            };
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl fmt::Debug for SysFsAdapterIterator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (start, end) = self.size_hint();
+        f.debug_struct("LinuxAdapterIterator")
+            .field("start", &start)
+            .field("end", &end)
+            .finish()
+    }
+}
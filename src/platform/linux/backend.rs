@@ -0,0 +1,419 @@
+//! Runtime selection between the sysfs and UPower backends.
+//!
+//! Available with the `upower` feature only,
+//! otherwise sysfs types are used directly.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+use super::{SysFsDevice, SysFsIterator, SysFsManager};
+use crate::platform::traits::*;
+use crate::platform::upower::{UPowerDevice, UPowerIterator, UPowerManager};
+use crate::units::{
+    ElectricCharge, ElectricCurrent, ElectricPotential, Energy, Power, Ratio,
+    ThermodynamicTemperature, Time,
+};
+use crate::{
+    Adapter, Backend, CapacityLevel, ChargeBehaviour, ChargeThresholds, ChargeType, DataProvenance,
+    DesignVoltageSource, DeviceKind, Error, Health, Identity, ManagerBuilder, ManufactureDate,
+    PowerFlow, Result, SanitizePolicy, Scope, State, Technology,
+};
+
+#[derive(Debug)]
+pub enum BackendManager {
+    SysFs(Arc<SysFsManager>),
+    UPower(Arc<UPowerManager>),
+}
+
+impl BackendManager {
+    fn sysfs(&self) -> Result<&SysFsManager> {
+        match self {
+            BackendManager::SysFs(manager) => Ok(manager),
+            BackendManager::UPower(_) => Err(Error::unsupported(
+                "Operation is not supported by the UPower backend",
+            )),
+        }
+    }
+
+    // Methods used by the `Monitor`, which works with sysfs only
+
+    pub fn is_battery(&self, name: &str) -> Result<bool> {
+        self.sysfs()?.is_battery(name)
+    }
+
+    pub fn device(&self, name: &str) -> Result<BackendDevice> {
+        self.sysfs()?
+            .device(name)
+            .map(|device| BackendDevice::SysFs(Box::new(device)))
+    }
+}
+
+impl BatteryManager for BackendManager {
+    type Iterator = BackendIterator;
+
+    fn new() -> Result<Self> {
+        Self::with_builder(&ManagerBuilder::default())
+    }
+
+    fn with_builder(builder: &ManagerBuilder) -> Result<Self> {
+        let backend = match builder.get_backend() {
+            Backend::Auto if builder.get_sysfs_root().is_some() => Backend::SysFs,
+            Backend::Auto => {
+                let manager = SysFsManager::with_builder(builder)?;
+                if manager.path().read_dir().is_ok() {
+                    return Ok(BackendManager::SysFs(Arc::new(manager)));
+                }
+                Backend::UPower
+            }
+            backend => backend,
+        };
+
+        match backend {
+            Backend::UPower => {
+                let manager = UPowerManager::with_builder(builder)?;
+                Ok(BackendManager::UPower(Arc::new(manager)))
+            }
+            _ => {
+                let manager = SysFsManager::with_builder(builder)?;
+                Ok(BackendManager::SysFs(Arc::new(manager)))
+            }
+        }
+    }
+
+    fn refresh(&self, device: &mut BackendDevice) -> Result<()> {
+        match (self, device) {
+            (BackendManager::SysFs(manager), BackendDevice::SysFs(device)) => {
+                manager.refresh(device)
+            }
+            (BackendManager::UPower(manager), BackendDevice::UPower(device)) => {
+                manager.refresh(device)
+            }
+            _ => Err(mismatched()),
+        }
+    }
+
+    fn battery(&self, name: &str) -> Result<Option<BackendDevice>> {
+        match self {
+            BackendManager::SysFs(manager) => Ok(manager
+                .battery(name)?
+                .map(|device| BackendDevice::SysFs(Box::new(device)))),
+            BackendManager::UPower(manager) => {
+                Ok(manager.battery(name)?.map(BackendDevice::UPower))
+            }
+        }
+    }
+
+    fn battery_names(&self) -> Result<HashSet<String>> {
+        self.sysfs()?.battery_names()
+    }
+
+    fn adapters(&self) -> Result<Box<dyn Iterator<Item = Result<Adapter>> + Send>> {
+        match self {
+            BackendManager::SysFs(manager) => manager.adapters(),
+            BackendManager::UPower(manager) => manager.adapters(),
+        }
+    }
+
+    fn set_charge_thresholds(
+        &self,
+        device: &BackendDevice,
+        start: Option<Ratio>,
+        end: Option<Ratio>,
+    ) -> Result<()> {
+        match (self, device) {
+            (BackendManager::SysFs(manager), BackendDevice::SysFs(device)) => {
+                manager.set_charge_thresholds(device, start, end)
+            }
+            (BackendManager::UPower(manager), BackendDevice::UPower(device)) => {
+                manager.set_charge_thresholds(device, start, end)
+            }
+            _ => Err(mismatched()),
+        }
+    }
+
+    fn set_charge_behaviour(
+        &self,
+        device: &BackendDevice,
+        behaviour: ChargeBehaviour,
+    ) -> Result<()> {
+        match (self, device) {
+            (BackendManager::SysFs(manager), BackendDevice::SysFs(device)) => {
+                manager.set_charge_behaviour(device, behaviour)
+            }
+            (BackendManager::UPower(manager), BackendDevice::UPower(device)) => {
+                manager.set_charge_behaviour(device, behaviour)
+            }
+            _ => Err(mismatched()),
+        }
+    }
+
+    fn set_charge_type(&self, device: &BackendDevice, charge_type: ChargeType) -> Result<()> {
+        match (self, device) {
+            (BackendManager::SysFs(manager), BackendDevice::SysFs(device)) => {
+                manager.set_charge_type(device, charge_type)
+            }
+            (BackendManager::UPower(manager), BackendDevice::UPower(device)) => {
+                manager.set_charge_type(device, charge_type)
+            }
+            _ => Err(mismatched()),
+        }
+    }
+}
+
+fn mismatched() -> Error {
+    Error::invalid_input("Battery was created by the manager with a different backend")
+}
+
+pub enum BackendIterator {
+    SysFs(SysFsIterator),
+    UPower(UPowerIterator),
+}
+
+impl BatteryIterator for BackendIterator {
+    type Manager = BackendManager;
+    type Device = BackendDevice;
+
+    fn new(manager: Arc<Self::Manager>) -> Result<Self> {
+        match &*manager {
+            BackendManager::SysFs(inner) => {
+                SysFsIterator::new(inner.clone()).map(BackendIterator::SysFs)
+            }
+            BackendManager::UPower(inner) => {
+                UPowerIterator::new(inner.clone()).map(BackendIterator::UPower)
+            }
+        }
+    }
+}
+
+impl Iterator for BackendIterator {
+    type Item = Result<<Self as BatteryIterator>::Device>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            BackendIterator::SysFs(inner) => inner
+                .next()
+                .map(|r| r.map(|device| BackendDevice::SysFs(Box::new(device)))),
+            BackendIterator::UPower(inner) => inner.next().map(|r| r.map(BackendDevice::UPower)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            BackendIterator::SysFs(inner) => inner.size_hint(),
+            BackendIterator::UPower(inner) => inner.size_hint(),
+        }
+    }
+}
+
+impl fmt::Debug for BackendIterator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BackendIterator::SysFs(inner) => inner.fmt(f),
+            BackendIterator::UPower(inner) => inner.fmt(f),
+        }
+    }
+}
+
+// Boxed, since sysfs device is much larger than the UPower one
+pub enum BackendDevice {
+    SysFs(Box<SysFsDevice>),
+    UPower(UPowerDevice),
+}
+
+/// Calls the same method for any of the wrapped devices.
+macro_rules! dispatch {
+    ($self:ident.$method:ident($($arg:expr),*)) => {
+        match $self {
+            BackendDevice::SysFs(device) => device.$method($($arg),*),
+            BackendDevice::UPower(device) => device.$method($($arg),*),
+        }
+    };
+}
+
+impl BatteryDevice for BackendDevice {
+    fn state_of_health(&self) -> Ratio {
+        dispatch!(self.state_of_health())
+    }
+
+    fn state_of_charge(&self) -> Ratio {
+        dispatch!(self.state_of_charge())
+    }
+
+    fn energy(&self) -> Energy {
+        dispatch!(self.energy())
+    }
+
+    fn energy_full(&self) -> Energy {
+        dispatch!(self.energy_full())
+    }
+
+    fn energy_full_design(&self) -> Energy {
+        dispatch!(self.energy_full_design())
+    }
+
+    fn energy_rate(&self) -> Power {
+        dispatch!(self.energy_rate())
+    }
+
+    fn state(&self) -> State {
+        dispatch!(self.state())
+    }
+
+    fn voltage(&self) -> ElectricPotential {
+        dispatch!(self.voltage())
+    }
+
+    fn temperature(&self) -> Option<ThermodynamicTemperature> {
+        dispatch!(self.temperature())
+    }
+
+    fn vendor(&self) -> Option<&str> {
+        dispatch!(self.vendor())
+    }
+
+    fn model(&self) -> Option<&str> {
+        dispatch!(self.model())
+    }
+
+    fn serial_number(&self) -> Option<&str> {
+        dispatch!(self.serial_number())
+    }
+
+    fn name(&self) -> String {
+        dispatch!(self.name())
+    }
+
+    fn technology(&self) -> Technology {
+        dispatch!(self.technology())
+    }
+
+    fn cycle_count(&self) -> Option<u32> {
+        dispatch!(self.cycle_count())
+    }
+
+    fn manufacture_date(&self) -> Option<ManufactureDate> {
+        dispatch!(self.manufacture_date())
+    }
+
+    fn identity(&self) -> Identity {
+        dispatch!(self.identity())
+    }
+
+    fn capacity_level(&self) -> Option<CapacityLevel> {
+        dispatch!(self.capacity_level())
+    }
+
+    fn power_flow(&self) -> PowerFlow {
+        dispatch!(self.power_flow())
+    }
+
+    fn current(&self) -> Option<ElectricCurrent> {
+        dispatch!(self.current())
+    }
+
+    fn charge(&self) -> Option<ElectricCharge> {
+        dispatch!(self.charge())
+    }
+
+    fn charge_full(&self) -> Option<ElectricCharge> {
+        dispatch!(self.charge_full())
+    }
+
+    fn charge_full_design(&self) -> Option<ElectricCharge> {
+        dispatch!(self.charge_full_design())
+    }
+
+    fn charge_counter(&self) -> Option<ElectricCharge> {
+        dispatch!(self.charge_counter())
+    }
+
+    fn voltage_min_design(&self) -> Option<ElectricPotential> {
+        dispatch!(self.voltage_min_design())
+    }
+
+    fn voltage_max_design(&self) -> Option<ElectricPotential> {
+        dispatch!(self.voltage_max_design())
+    }
+
+    fn voltage_ocv(&self) -> Option<ElectricPotential> {
+        dispatch!(self.voltage_ocv())
+    }
+
+    fn voltage_avg(&self) -> Option<ElectricPotential> {
+        dispatch!(self.voltage_avg())
+    }
+
+    fn design_voltage_source(&self) -> Option<DesignVoltageSource> {
+        dispatch!(self.design_voltage_source())
+    }
+
+    fn health(&self) -> Option<Health> {
+        dispatch!(self.health())
+    }
+
+    fn sanitize_policy(&self) -> SanitizePolicy {
+        dispatch!(self.sanitize_policy())
+    }
+
+    fn provenance(&self) -> DataProvenance {
+        dispatch!(self.provenance())
+    }
+
+    fn capacity_error_margin(&self) -> Option<Ratio> {
+        dispatch!(self.capacity_error_margin())
+    }
+
+    fn raw_properties(&self) -> &BTreeMap<String, String> {
+        dispatch!(self.raw_properties())
+    }
+
+    fn read_property(&self, name: &str) -> Result<Option<String>> {
+        dispatch!(self.read_property(name))
+    }
+
+    fn scope(&self) -> Scope {
+        dispatch!(self.scope())
+    }
+
+    fn device_kind(&self) -> Option<DeviceKind> {
+        dispatch!(self.device_kind())
+    }
+
+    fn charge_thresholds(&self) -> Option<ChargeThresholds> {
+        dispatch!(self.charge_thresholds())
+    }
+
+    fn charge_behaviour(&self) -> Option<ChargeBehaviour> {
+        dispatch!(self.charge_behaviour())
+    }
+
+    fn available_charge_behaviours(&self) -> &[ChargeBehaviour] {
+        dispatch!(self.available_charge_behaviours())
+    }
+
+    fn charge_type(&self) -> Option<ChargeType> {
+        dispatch!(self.charge_type())
+    }
+
+    fn available_charge_types(&self) -> &[ChargeType] {
+        dispatch!(self.available_charge_types())
+    }
+
+    fn time_to_full(&self) -> Option<Time> {
+        dispatch!(self.time_to_full())
+    }
+
+    fn time_to_empty(&self) -> Option<Time> {
+        dispatch!(self.time_to_empty())
+    }
+}
+
+impl fmt::Debug for BackendDevice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BackendDevice::SysFs(inner) => inner.fmt(f),
+            BackendDevice::UPower(inner) => inner.fmt(f),
+        }
+    }
+}
@@ -1,53 +1,102 @@
+use std::collections::BTreeMap;
 use std::fmt;
-use std::io;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use num_traits::identities::Zero;
+use num_traits::ToPrimitive;
 
 use crate::platform::traits::*;
-use crate::units::{ElectricPotential, Energy, Power, Ratio, ThermodynamicTemperature};
-use crate::{Error, Result, State, Technology};
+use crate::units::ratio::percent;
+use crate::units::{
+    ElectricCharge, ElectricCurrent, ElectricPotential, Energy, Power, Ratio,
+    ThermodynamicTemperature,
+};
+use crate::{
+    CapacityLevel, ChargeBehaviour, ChargeThresholds, ChargeType, DataProvenance,
+    DesignVoltageSource, DeviceKind, Error, Health, Identity, ManufactureDate, PowerFlow,
+    Provenance, Result, SanitizePolicy, Scope, State, Technology,
+};
 
-use super::sysfs::{fs, DataBuilder, InstantData, Scope, Type};
+use super::rate::RateSampler;
+use super::sysfs::{
+    fs, Attributes, DataBuilder, InstantData, Type, CHARGE_END_THRESHOLD, CHARGE_START_THRESHOLD,
+    CHARGE_TYPE,
+};
 
 pub struct SysFsDevice {
     root: PathBuf,
     source: InstantData,
+    sampler: RateSampler,
+    policy: SanitizePolicy,
+    // Read on demand only, since it requires to read all the attribute files
+    raw_properties: OnceLock<BTreeMap<String, String>>,
 
     // These fields are "cached" outside from DataBuilder/InstantData,
     // since they're can't change with refresh
     vendor: Option<String>,
     model: Option<String>,
     serial_number: Option<String>,
+    manufacture_date: Option<ManufactureDate>,
+    battery_type: Option<String>,
     technology: Technology,
+    scope: Scope,
+    device_kind: Option<DeviceKind>,
 }
 
 impl SysFsDevice {
-    pub fn is_system_battery<T: AsRef<Path>>(path: T) -> Result<bool> {
+    /// Peripheral batteries (with the `Device` scope) are considered
+    /// only if `include_peripherals` is set.
+    pub fn is_battery<T: AsRef<Path>>(path: T, include_peripherals: bool) -> Result<bool> {
         let path = path.as_ref();
-        if fs::type_(path.join("type"))? == Type::Battery
-            && fs::scope(path.join("scope"))? == Scope::System
-        {
-            return Ok(true);
+        if fs::type_(path.join("type"))? != Type::Battery {
+            return Ok(false);
         }
 
-        Ok(false)
+        match fs::scope(path.join("scope"))? {
+            Scope::System => Ok(true),
+            Scope::Device => Ok(include_peripherals),
+            _ => Ok(false),
+        }
     }
 
+    #[cfg(test)]
     pub fn try_from(root: PathBuf) -> Result<SysFsDevice> {
-        let builder = DataBuilder::new(&root);
+        Self::with_policy(root, SanitizePolicy::default())
+    }
+
+    pub fn with_policy(root: PathBuf, policy: SanitizePolicy) -> Result<SysFsDevice> {
+        let builder = DataBuilder::new(&root, policy);
         let vendor = builder.manufacturer()?;
         let model = builder.model()?;
         let serial_number = builder.serial_number()?;
+        let manufacture_date = builder.manufacture_date()?;
+        let battery_type = builder.battery_type()?;
         let technology = builder.technology()?;
+        let scope = builder.scope()?;
+        let device_kind = match scope {
+            Scope::Device => Some(builder.device_kind(model.as_deref())),
+            _ => None,
+        };
 
         let source = builder.collect()?;
+        let sampler = RateSampler::new(source.energy, source.state, Instant::now());
 
         Ok(SysFsDevice {
             root,
             source,
+            sampler,
+            policy,
+            raw_properties: OnceLock::new(),
             vendor,
             model,
             serial_number,
+            manufacture_date,
+            battery_type,
             technology,
+            scope,
+            device_kind,
         })
     }
 
@@ -56,20 +105,103 @@ impl SysFsDevice {
         // still exists and accessible.
         // See https://github.com/svartalf/rust-battery/issues/29
         if self.root.is_dir() {
-            let builder = DataBuilder::new(&self.root);
+            let builder = DataBuilder::new(&self.root, self.policy);
             self.source = builder.collect()?;
+            self.raw_properties = OnceLock::new();
+
+            // Drivers with no `power_now` and `current_now` attributes
+            // are reporting zero rate, calculating it from the energy changes instead
+            let rate = self
+                .sampler
+                .update(self.source.energy, self.source.state, Instant::now());
+            if let Some(rate) = rate.map(|rate| self.policy.energy_rate(rate)) {
+                if self.source.energy_rate.is_zero() {
+                    self.source.energy_rate = rate;
+                    self.source.power_flow = PowerFlow::new(self.source.state, rate);
+                    self.source.provenance.energy_rate = Provenance::Estimated;
+                }
+            }
 
             Ok(())
         } else {
-            let inner = io::Error::from(io::ErrorKind::NotFound);
-            let e = Error::new(
-                inner,
-                format!("Device directory `{:?}` is missing", self.root),
-            );
+            let e = Error::device_removed(format!("Device directory `{:?}` is missing", self.root));
 
             Err(e)
         }
     }
+
+    pub fn set_charge_thresholds(&self, start: Option<Ratio>, end: Option<Ratio>) -> Result<()> {
+        let start = match start {
+            Some(value) => Some((self.attribute(&CHARGE_START_THRESHOLD)?, value)),
+            None => None,
+        };
+        let end = match end {
+            Some(value) => Some((self.attribute(&CHARGE_END_THRESHOLD)?, value)),
+            None => None,
+        };
+
+        // Drivers are validating the new threshold value against the current value
+        // of the another one, so the order of writes matters when both are changing.
+        // Raising the end threshold goes first, lowering it goes last.
+        let current_end = DataBuilder::new(&self.root, self.policy)
+            .charge_thresholds()?
+            .and_then(|thresholds| thresholds.end());
+        let end_first = match (&end, current_end) {
+            (Some((_, value)), Some(current)) => *value >= current,
+            _ => false,
+        };
+        let writes = if end_first {
+            [end, start]
+        } else {
+            [start, end]
+        };
+
+        for (path, value) in writes.iter().flatten() {
+            // Values are in 0…100 range already
+            let value = value.get::<percent>().round().to_u8().unwrap_or_default();
+            fs::set(path, value)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn set_charge_behaviour(&self, behaviour: ChargeBehaviour) -> Result<()> {
+        let path = self.attribute(&["charge_behaviour"])?;
+
+        fs::set(path, behaviour)
+    }
+
+    pub fn set_charge_type(&self, charge_type: ChargeType) -> Result<()> {
+        let path = self.attribute(&CHARGE_TYPE)?;
+        // Kernel expects the same values it reports
+        let value = match charge_type {
+            ChargeType::Unknown => "Unknown",
+            ChargeType::NotAvailable => "N/A",
+            ChargeType::Trickle => "Trickle",
+            ChargeType::Fast => "Fast",
+            ChargeType::Standard => "Standard",
+            ChargeType::Adaptive => "Adaptive",
+            ChargeType::Custom => "Custom",
+            ChargeType::LongLife => "Long Life",
+            ChargeType::Bypass => "Bypass",
+        };
+
+        fs::set(path, value)
+    }
+
+    /// Returns path to the first existing attribute file from the `filenames`.
+    fn attribute(&self, filenames: &[&str]) -> Result<PathBuf> {
+        filenames
+            .iter()
+            .map(|filename| self.root.join(filename))
+            .find(|path| path.is_file())
+            .ok_or_else(|| {
+                Error::unsupported(format!(
+                    "Device does not support the `{}` attribute",
+                    filenames[0]
+                ))
+            })
+    }
 }
 
 impl BatteryDevice for SysFsDevice {
@@ -105,6 +237,58 @@ impl BatteryDevice for SysFsDevice {
         self.source.voltage
     }
 
+    fn capacity_level(&self) -> Option<CapacityLevel> {
+        self.source.capacity_level
+    }
+
+    fn power_flow(&self) -> PowerFlow {
+        self.source.power_flow
+    }
+
+    fn current(&self) -> Option<ElectricCurrent> {
+        self.source.current
+    }
+
+    fn charge(&self) -> Option<ElectricCharge> {
+        self.source.charge
+    }
+
+    fn charge_full(&self) -> Option<ElectricCharge> {
+        self.source.charge_full
+    }
+
+    fn charge_full_design(&self) -> Option<ElectricCharge> {
+        self.source.charge_full_design
+    }
+
+    fn charge_counter(&self) -> Option<ElectricCharge> {
+        self.source.charge_counter
+    }
+
+    fn voltage_min_design(&self) -> Option<ElectricPotential> {
+        self.source.voltage_min_design
+    }
+
+    fn voltage_max_design(&self) -> Option<ElectricPotential> {
+        self.source.voltage_max_design
+    }
+
+    fn voltage_ocv(&self) -> Option<ElectricPotential> {
+        self.source.voltage_ocv
+    }
+
+    fn voltage_avg(&self) -> Option<ElectricPotential> {
+        self.source.voltage_avg
+    }
+
+    fn design_voltage_source(&self) -> Option<DesignVoltageSource> {
+        self.source.design_voltage_source
+    }
+
+    fn health(&self) -> Option<Health> {
+        self.source.health
+    }
+
     fn temperature(&self) -> Option<ThermodynamicTemperature> {
         self.source.temperature
     }
@@ -121,6 +305,27 @@ impl BatteryDevice for SysFsDevice {
         self.serial_number.as_ref().map(AsRef::as_ref)
     }
 
+    fn name(&self) -> String {
+        self.root
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    fn manufacture_date(&self) -> Option<ManufactureDate> {
+        self.manufacture_date
+    }
+
+    fn identity(&self) -> Identity {
+        Identity {
+            vendor: self.vendor.clone(),
+            model: self.model.clone(),
+            serial_number: self.serial_number.clone(),
+            manufacture_date: self.manufacture_date,
+            battery_type: self.battery_type.clone(),
+        }
+    }
+
     fn technology(&self) -> Technology {
         self.technology
     }
@@ -128,6 +333,74 @@ impl BatteryDevice for SysFsDevice {
     fn cycle_count(&self) -> Option<u32> {
         self.source.cycle_count
     }
+
+    fn scope(&self) -> Scope {
+        self.scope
+    }
+
+    fn sanitize_policy(&self) -> SanitizePolicy {
+        self.policy
+    }
+
+    fn provenance(&self) -> DataProvenance {
+        self.source.provenance
+    }
+
+    fn capacity_error_margin(&self) -> Option<Ratio> {
+        self.source.capacity_error_margin
+    }
+
+    fn raw_properties(&self) -> &BTreeMap<String, String> {
+        self.raw_properties
+            .get_or_init(|| Attributes::new(&self.root).all())
+    }
+
+    fn read_property(&self, name: &str) -> Result<Option<String>> {
+        if name.is_empty() || name.contains('/') || name == "." || name == ".." {
+            return Err(Error::invalid_input(format!(
+                "Invalid power supply property name `{}`",
+                name
+            )));
+        }
+
+        fs::get_string(self.root.join(name))
+    }
+
+    fn device_kind(&self) -> Option<DeviceKind> {
+        self.device_kind
+    }
+
+    fn charge_thresholds(&self) -> Option<ChargeThresholds> {
+        self.source.charge_thresholds
+    }
+
+    fn charge_behaviour(&self) -> Option<ChargeBehaviour> {
+        self.source
+            .charge_behaviour
+            .as_ref()
+            .and_then(|choices| choices.active)
+    }
+
+    fn available_charge_behaviours(&self) -> &[ChargeBehaviour] {
+        match &self.source.charge_behaviour {
+            Some(choices) => &choices.available,
+            None => &[],
+        }
+    }
+
+    fn charge_type(&self) -> Option<ChargeType> {
+        self.source
+            .charge_type
+            .as_ref()
+            .and_then(|choices| choices.active)
+    }
+
+    fn available_charge_types(&self) -> &[ChargeType] {
+        match &self.source.charge_type {
+            Some(choices) => &choices.available,
+            None => &[],
+        }
+    }
 }
 
 impl fmt::Debug for SysFsDevice {
@@ -7,7 +7,6 @@ use crate::platform::traits::*;
 use crate::Result;
 
 pub struct SysFsIterator {
-    #[allow(dead_code)]
     manager: Arc<SysFsManager>,
     entries: ReadDir,
 }
@@ -34,8 +33,8 @@ impl Iterator for SysFsIterator {
                 Some(Err(e)) => Some(Err(e.into())),
                 Some(Ok(entry)) => {
                     let path = entry.path();
-                    match SysFsDevice::is_system_battery(&path) {
-                        Ok(true) => Some(SysFsDevice::try_from(path)),
+                    match SysFsDevice::is_battery(&path, self.manager.include_peripherals()) {
+                        Ok(true) => Some(SysFsDevice::with_policy(path, self.manager.policy())),
                         Ok(false) => continue,
                         Err(e) => Some(Err(e)),
                     }
@@ -1,33 +1,122 @@
+use std::collections::HashSet;
+use std::fs;
 use std::path::{Path, PathBuf};
 
+use super::adapter::SysFsAdapterIterator;
 use super::device::SysFsDevice;
 use super::iterator::SysFsIterator;
 use crate::platform::traits::*;
-use crate::Result;
+use crate::units::Ratio;
+use crate::{Adapter, ChargeBehaviour, ChargeType, ManagerBuilder, Result, SanitizePolicy};
 
 static SYSFS_ROOT: &str = "/sys/class/power_supply";
 
 #[derive(Debug)]
 pub struct SysFsManager {
     root: PathBuf,
+    include_peripherals: bool,
+    policy: SanitizePolicy,
 }
 
 impl SysFsManager {
+    pub fn with_root(root: PathBuf) -> SysFsManager {
+        SysFsManager {
+            root,
+            include_peripherals: false,
+            policy: SanitizePolicy::default(),
+        }
+    }
+
     pub fn path(&self) -> &Path {
         self.root.as_path()
     }
+
+    pub fn include_peripherals(&self) -> bool {
+        self.include_peripherals
+    }
+
+    pub fn policy(&self) -> SanitizePolicy {
+        self.policy
+    }
+
+    /// Checks if power supply with this `name` is a battery, which should be enumerated.
+    pub fn is_battery(&self, name: &str) -> Result<bool> {
+        SysFsDevice::is_battery(self.root.join(name), self.include_peripherals)
+    }
+
+    pub fn device(&self, name: &str) -> Result<SysFsDevice> {
+        SysFsDevice::with_policy(self.root.join(name), self.policy)
+    }
 }
 
 impl BatteryManager for SysFsManager {
     type Iterator = SysFsIterator;
 
     fn new() -> Result<Self> {
-        Ok(Self {
-            root: PathBuf::from(SYSFS_ROOT),
-        })
+        Ok(Self::with_root(PathBuf::from(SYSFS_ROOT)))
+    }
+
+    fn with_builder(builder: &ManagerBuilder) -> Result<Self> {
+        let mut manager = match builder.get_sysfs_root() {
+            Some(root) => Self::with_root(root.to_path_buf()),
+            None => Self::new()?,
+        };
+        manager.include_peripherals = builder.get_include_peripherals();
+        manager.policy = builder.get_sanitize_policy();
+
+        Ok(manager)
     }
 
     fn refresh(&self, device: &mut SysFsDevice) -> Result<()> {
         device.refresh()
     }
+
+    fn battery(&self, name: &str) -> Result<Option<SysFsDevice>> {
+        // Name should not point outside of the root directory
+        if name.is_empty() || name.contains('/') || name == "." || name == ".." {
+            return Ok(None);
+        }
+        if !self.root.join(name).is_dir() || !self.is_battery(name)? {
+            return Ok(None);
+        }
+
+        self.device(name).map(Some)
+    }
+
+    fn battery_names(&self) -> Result<HashSet<String>> {
+        let mut names = HashSet::new();
+        for entry in fs::read_dir(self.path())? {
+            let name = entry?.file_name().to_string_lossy().into_owned();
+            // Unreadable power supply should not hide the rest of them,
+            // same as it does not fail the enumeration of the rest of batteries
+            if self.is_battery(&name).unwrap_or(false) {
+                names.insert(name);
+            }
+        }
+
+        Ok(names)
+    }
+
+    fn adapters(&self) -> Result<Box<dyn Iterator<Item = Result<Adapter>> + Send>> {
+        let iterator = SysFsAdapterIterator::new(self.path())?;
+
+        Ok(Box::new(iterator))
+    }
+
+    fn set_charge_thresholds(
+        &self,
+        device: &SysFsDevice,
+        start: Option<Ratio>,
+        end: Option<Ratio>,
+    ) -> Result<()> {
+        device.set_charge_thresholds(start, end)
+    }
+
+    fn set_charge_behaviour(&self, device: &SysFsDevice, behaviour: ChargeBehaviour) -> Result<()> {
+        device.set_charge_behaviour(behaviour)
+    }
+
+    fn set_charge_type(&self, device: &SysFsDevice, charge_type: ChargeType) -> Result<()> {
+        device.set_charge_type(charge_type)
+    }
 }
@@ -1,8 +1,15 @@
+mod adapter;
+#[cfg(feature = "upower")]
+mod backend;
 mod device;
 mod iterator;
 mod manager;
+pub mod netlink;
+mod rate;
 mod sysfs;
 
+#[cfg(feature = "upower")]
+pub use self::backend::{BackendDevice, BackendIterator, BackendManager};
 pub use self::device::SysFsDevice;
 pub use self::iterator::SysFsIterator;
 pub use self::manager::SysFsManager;
@@ -0,0 +1,117 @@
+use std::io;
+use std::mem;
+use std::os::unix::io::{AsRawFd, FromRawFd, OwnedFd};
+
+use crate::{Result, UEventSource};
+
+// Kernel is the only sender for this multicast group
+const KERNEL_EVENTS_GROUP: u32 = 1;
+
+// Large enough for any `power_supply` uevent message
+const BUFFER_SIZE: usize = 8192;
+
+/// Socket receiving uevent messages from the kernel.
+#[derive(Debug)]
+pub struct NetlinkSocket(OwnedFd);
+
+impl NetlinkSocket {
+    pub fn new() -> Result<NetlinkSocket> {
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_DGRAM | libc::SOCK_CLOEXEC,
+                libc::NETLINK_KOBJECT_UEVENT,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        let socket = NetlinkSocket(unsafe { OwnedFd::from_raw_fd(fd) });
+
+        let mut addr: libc::sockaddr_nl = unsafe { mem::zeroed() };
+        addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+        addr.nl_groups = KERNEL_EVENTS_GROUP;
+        let result = unsafe {
+            libc::bind(
+                socket.0.as_raw_fd(),
+                &addr as *const libc::sockaddr_nl as *const libc::sockaddr,
+                mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t,
+            )
+        };
+        if result < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+
+        Ok(socket)
+    }
+}
+
+impl UEventSource for NetlinkSocket {
+    fn recv(&mut self) -> Result<Option<Vec<u8>>> {
+        let mut buffer = vec![0u8; BUFFER_SIZE];
+        loop {
+            let mut addr: libc::sockaddr_nl = unsafe { mem::zeroed() };
+            let mut addr_len = mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t;
+            let received = unsafe {
+                libc::recvfrom(
+                    self.0.as_raw_fd(),
+                    buffer.as_mut_ptr() as *mut libc::c_void,
+                    buffer.len(),
+                    0,
+                    &mut addr as *mut libc::sockaddr_nl as *mut libc::sockaddr,
+                    &mut addr_len,
+                )
+            };
+            if received < 0 {
+                let e = io::Error::last_os_error();
+                if e.kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(e.into());
+            }
+            // Messages from the userspace processes are not trusted
+            if addr.nl_pid != 0 {
+                continue;
+            }
+
+            buffer.truncate(received as usize);
+            return Ok(Some(buffer));
+        }
+    }
+}
+
+/// Kernel uevent message, only fields needed for the monitoring are parsed.
+#[derive(Debug, Eq, PartialEq)]
+pub struct UEvent {
+    pub action: String,
+    pub subsystem: String,
+    /// Device name, last component of the `DEVPATH`
+    pub name: String,
+}
+
+impl UEvent {
+    pub fn parse(message: &[u8]) -> Option<UEvent> {
+        let message = String::from_utf8_lossy(message);
+        let mut parts = message.split('\0');
+        // `{action}@{devpath}` header
+        if !parts.next()?.contains('@') {
+            return None;
+        }
+
+        let (mut action, mut subsystem, mut devpath) = (None, None, None);
+        for part in parts {
+            match part.split_once('=') {
+                Some(("ACTION", value)) => action = Some(value),
+                Some(("SUBSYSTEM", value)) => subsystem = Some(value),
+                Some(("DEVPATH", value)) => devpath = Some(value),
+                _ => continue,
+            }
+        }
+
+        Some(UEvent {
+            action: action?.to_string(),
+            subsystem: subsystem?.to_string(),
+            name: devpath?.rsplit('/').next()?.to_string(),
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use std::time::{Duration, Instant};
+
+use num_traits::identities::Zero;
+
+use crate::units::{Energy, Power};
+use crate::State;
+
+/// Energy samples older than this are required to calculate the rate,
+/// since fuel gauges are updating the energy value in the coarse steps.
+pub static MIN_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Energy rate calculated from the energy values difference between the refreshes,
+/// used for the devices which are reporting neither `power_now`, nor `current_now`.
+///
+/// Same thing as the upower `up_device_supply_calculate_rate` function does.
+#[derive(Debug)]
+pub struct RateSampler {
+    energy: Energy,
+    state: State,
+    timestamp: Instant,
+    rate: Option<Power>,
+}
+
+impl RateSampler {
+    pub fn new(energy: Energy, state: State, timestamp: Instant) -> RateSampler {
+        RateSampler {
+            energy,
+            state,
+            timestamp,
+            rate: None,
+        }
+    }
+
+    /// Stores the new energy sample and returns the calculated rate, if there is one already.
+    ///
+    /// Returned rate is not sanitized, same as the one reported by driver should be.
+    ///
+    /// Samples are dropped if battery state changes, because the energy flow direction
+    /// changes too and the old samples are no longer relevant.
+    pub fn update(&mut self, energy: Energy, state: State, timestamp: Instant) -> Option<Power> {
+        let is_flowing = matches!(state, State::Charging | State::Discharging);
+        if state != self.state || !is_flowing || energy.is_zero() {
+            *self = RateSampler::new(energy, state, timestamp);
+            return None;
+        }
+
+        let elapsed = timestamp.saturating_duration_since(self.timestamp);
+        // Waiting for the energy value to change, or for the enough time to pass,
+        // keeping the previously calculated rate meanwhile
+        if energy == self.energy || elapsed < MIN_INTERVAL {
+            return self.rate;
+        }
+
+        self.rate = Some((energy - self.energy).abs() / second!(elapsed.as_secs_f32()));
+        self.energy = energy;
+        self.timestamp = timestamp;
+
+        self.rate
+    }
+}
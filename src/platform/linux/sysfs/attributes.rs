@@ -0,0 +1,195 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use super::{fs, Choices};
+use crate::units::{ElectricCharge, ElectricCurrent, ElectricPotential, Energy, Power};
+use crate::Result;
+
+/// Power supply attributes from the `/sys/class/power_supply/{name}/` directory.
+///
+/// Most of the attributes are read at once from the `uevent` file,
+/// which contains `POWER_SUPPLY_{NAME}={value}` lines for each property
+/// the driver was able to report.
+/// Attributes missing there are read from the separate files.
+pub struct Attributes {
+    root: PathBuf,
+    uevent: HashMap<String, String>,
+}
+
+impl Attributes {
+    pub fn new<T: Into<PathBuf>>(root: T) -> Attributes {
+        let root = root.into();
+        // Kernel fails the whole `uevent` read if any property read fails
+        // with an error other than `ENODEV` or `ENODATA`,
+        // individual files are read as a fallback in that case.
+        let uevent = match fs::get_string(root.join("uevent")) {
+            Ok(Some(content)) => parse_uevent(&content),
+            _ => HashMap::new(),
+        };
+
+        Attributes { root, uevent }
+    }
+
+    pub fn path(&self) -> &Path {
+        self.root.as_path()
+    }
+
+    /// Same as `fs::get_string`, but looks into the `uevent` first.
+    pub fn get_string(&self, name: &str) -> Result<Option<String>> {
+        match self.uevent.get(name) {
+            Some(value) => Ok(Some(value.clone())),
+            None => fs::get_string(self.root.join(name)),
+        }
+    }
+
+    /// Same as `fs::get`, but looks into the `uevent` first.
+    pub fn get<V>(&self, name: &str) -> Result<Option<V>>
+    where
+        V: FromStr,
+        <V as FromStr>::Err: error::Error + Sync + Send,
+    {
+        match self.get_string(name) {
+            Ok(Some(ref value)) => match V::from_str(value) {
+                Ok(result) => Ok(Some(result)),
+                Err(_) => Ok(None),
+            },
+            Ok(None) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// All readable attributes, including the ones which are not used by the crate.
+    ///
+    /// Files which are failing to read (ex. write-only ones) are skipped.
+    pub fn all(&self) -> BTreeMap<String, String> {
+        let mut attributes = self
+            .uevent
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect::<BTreeMap<_, _>>();
+
+        let entries = match std::fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(_) => return attributes,
+        };
+        for entry in entries.flatten() {
+            let is_file = entry
+                .file_type()
+                .map(|type_| type_.is_file())
+                .unwrap_or(false);
+            let name = match entry.file_name().into_string() {
+                Ok(name) if is_file && name != "uevent" => name,
+                _ => continue,
+            };
+            if attributes.contains_key(&name) {
+                continue;
+            }
+            if let Ok(Some(value)) = fs::get_string(entry.path()) {
+                attributes.insert(name, value);
+            }
+        }
+
+        attributes
+    }
+
+    /// Read attribute in the "available values" format.
+    ///
+    /// `uevent` contains the active value only, so the attribute file is always read.
+    pub fn choices<V>(&self, name: &str) -> Result<Option<Choices<V>>>
+    where
+        V: FromStr + Clone,
+    {
+        fs::get(self.root.join(name))
+    }
+
+    /// Read active value of the attribute in the "available values" format.
+    pub fn selected<V>(&self, name: &str) -> Result<Option<V>>
+    where
+        V: FromStr + Clone,
+    {
+        match self.choices::<V>(name) {
+            Ok(Some(choices)) => Ok(choices.active),
+            Ok(None) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Read µWh value from the `energy_` attribute and convert into `Energy` type.
+    pub fn energy(&self, name: &str) -> Result<Option<Energy>> {
+        debug_assert!(name.starts_with("energy_"));
+
+        match self.get::<f32>(name) {
+            Ok(Some(value_uwh)) => Ok(Some(microwatt_hour!(value_uwh))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Read µAh value from the `charge_` attribute and convert into `ElectricCharge` type.
+    pub fn charge(&self, name: &str) -> Result<Option<ElectricCharge>> {
+        debug_assert!(name.starts_with("charge_"));
+
+        match self.get::<f32>(name) {
+            Ok(Some(value_uah)) if value_uah > 1.0 => Ok(Some(microampere_hour!(value_uah))),
+            Ok(Some(_)) => Ok(None),
+            Ok(None) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Read µV value from the `voltage_` attribute and convert into `ElectricPotential` type.
+    pub fn voltage(&self, name: &str) -> Result<Option<ElectricPotential>> {
+        debug_assert!(name.starts_with("voltage_"));
+
+        match self.get::<f32>(name) {
+            Ok(Some(value_uv)) if value_uv > 1.0 => Ok(Some(microvolt!(value_uv))),
+            Ok(Some(_)) => Ok(None),
+            Ok(None) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Read µA value from the `current_` or `input_current_` attribute
+    /// and convert into `ElectricCurrent` type.
+    pub fn current(&self, name: &str) -> Result<Option<ElectricCurrent>> {
+        debug_assert!(name.contains("current_"));
+
+        match self.get::<f32>(name) {
+            Ok(Some(value_ua)) if value_ua > 0.0 => Ok(Some(microampere!(value_ua))),
+            Ok(Some(_)) => Ok(None),
+            Ok(None) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Read µW value from the `power_` attribute and convert into `Power` type.
+    ///
+    /// Some drivers are reporting negative values while discharging, so the absolute value is used.
+    /// Zero value is treated as a missing one.
+    pub fn power(&self, name: &str) -> Result<Option<Power>> {
+        debug_assert!(name.starts_with("power_"));
+
+        match self.get::<f32>(name) {
+            Ok(Some(value_uw)) if value_uw != 0.0 => Ok(Some(microwatt!(value_uw.abs()))),
+            Ok(Some(_)) => Ok(None),
+            Ok(None) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Parse `POWER_SUPPLY_{NAME}={value}` lines into the `{name} => {value}` map,
+/// where `{name}` is lowercased to match the attribute file name.
+///
+/// Lines without the `POWER_SUPPLY_` prefix (ex. `DEVTYPE=...`) are skipped.
+fn parse_uevent(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .filter_map(|line| line.strip_prefix("POWER_SUPPLY_"))
+        .filter_map(|line| line.split_once('='))
+        .filter(|(_, value)| !value.starts_with('\0'))
+        .map(|(name, value)| (name.to_ascii_lowercase(), value.to_string()))
+        .collect()
+}
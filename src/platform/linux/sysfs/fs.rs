@@ -1,83 +1,16 @@
 use std::error;
+use std::fmt::Display;
 use std::io;
 use std::path::Path;
 use std::str::FromStr;
 
-use super::{Scope, Type};
-use crate::units::{ElectricCharge, ElectricPotential, Energy, Power};
-use crate::Result;
+use super::Type;
+use crate::{Error, Result, Scope};
 
 // From the `errno.h`.
 // Easier than building whole `libc` dep.
 const ENODEV: i32 = 19;
 
-/// Read µWh value from the `energy_` file and convert into `Energy` type.
-pub fn energy<T: AsRef<Path>>(path: T) -> Result<Option<Energy>> {
-    let path = path.as_ref();
-    debug_assert!(path
-        .file_name()
-        .unwrap()
-        .to_string_lossy()
-        .starts_with("energy_"));
-
-    match get::<f32, _>(path) {
-        Ok(Some(value_uwh)) => Ok(Some(microwatt_hour!(value_uwh))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(e),
-    }
-}
-
-/// Read µAh value from the `charge_` file and convert into `ElectricCharge` type.
-pub fn charge<T: AsRef<Path>>(path: T) -> Result<Option<ElectricCharge>> {
-    let path = path.as_ref();
-    debug_assert!(path
-        .file_name()
-        .unwrap()
-        .to_string_lossy()
-        .starts_with("charge_"));
-
-    match get::<f32, _>(path) {
-        Ok(Some(value_uah)) if value_uah > 1.0 => Ok(Some(microampere_hour!(value_uah))),
-        Ok(Some(_)) => Ok(None),
-        Ok(None) => Ok(None),
-        Err(e) => Err(e),
-    }
-}
-
-/// Read µV value from the `voltage_` file and convert into `ElectricPotential` type.
-pub fn voltage<T: AsRef<Path>>(path: T) -> Result<Option<ElectricPotential>> {
-    let path = path.as_ref();
-    debug_assert!(path
-        .file_name()
-        .unwrap()
-        .to_string_lossy()
-        .starts_with("voltage_"));
-
-    match get::<f32, _>(path) {
-        Ok(Some(value_uv)) if value_uv > 1.0 => Ok(Some(microvolt!(value_uv))),
-        Ok(Some(_)) => Ok(None),
-        Ok(None) => Ok(None),
-        Err(e) => Err(e),
-    }
-}
-
-/// Read µW value from the `power_` file and convert into `Power` type.
-pub fn power<T: AsRef<Path>>(path: T) -> Result<Option<Power>> {
-    let path = path.as_ref();
-    debug_assert!(path
-        .file_name()
-        .unwrap()
-        .to_string_lossy()
-        .starts_with("power_"));
-
-    match get::<f32, _>(path) {
-        Ok(Some(value_uw)) if value_uw > 10_000.0 => Ok(Some(microwatt!(value_uw))),
-        Ok(Some(_)) => Ok(None),
-        Ok(None) => Ok(None),
-        Err(e) => Err(e),
-    }
-}
-
 /// Read device `type` file and convert into `Type` enum.
 pub fn type_<T: AsRef<Path>>(path: T) -> Result<Type> {
     let path = path.as_ref();
@@ -91,6 +24,9 @@ pub fn type_<T: AsRef<Path>>(path: T) -> Result<Type> {
 }
 
 /// Read device `scope` file and convert into `Scope` enum.
+///
+/// A power supply which doesn't have a "scope" attribute should be assumed to
+/// have "System" scope.
 pub fn scope<T: AsRef<Path>>(path: T) -> Result<Scope> {
     let path = path.as_ref();
     debug_assert!(path.file_name().unwrap().to_string_lossy() == "scope");
@@ -146,3 +82,26 @@ where
         Err(e) => Err(e),
     }
 }
+
+/// Write the `value` into the existing attribute file.
+///
+/// Changing attributes usually requires root privileges,
+/// so the `PermissionDenied` error gets a more descriptive message.
+pub fn set<V, T>(path: T, value: V) -> Result<()>
+where
+    T: AsRef<Path>,
+    V: Display,
+{
+    let path = path.as_ref();
+    match std::fs::write(path, value.to_string()) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => Err(Error::new(
+            e,
+            format!(
+                "Unable to write `{}`, root privileges are required",
+                path.display()
+            ),
+        )),
+        Err(e) => Err(e.into()),
+    }
+}
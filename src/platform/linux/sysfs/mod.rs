@@ -1,11 +1,24 @@
 use std::io;
 use std::str::FromStr;
 
+use crate::AdapterType;
+
+mod attributes;
 pub mod fs;
 mod source;
 
+pub use self::attributes::Attributes;
 pub use self::source::{DataBuilder, InstantData};
 
+/// Charge thresholds attributes, modern name goes first and the legacy one next.
+pub static CHARGE_START_THRESHOLD: [&str; 2] =
+    ["charge_control_start_threshold", "charge_start_threshold"];
+pub static CHARGE_END_THRESHOLD: [&str; 2] =
+    ["charge_control_end_threshold", "charge_stop_threshold"];
+/// `charge_types` is in the "available values" format,
+/// while the older `charge_type` contains the active value only.
+pub static CHARGE_TYPE: [&str; 2] = ["charge_types", "charge_type"];
+
 #[allow(dead_code)]
 #[derive(Debug, Eq, PartialEq)]
 pub enum Type {
@@ -32,30 +45,83 @@ impl FromStr for Type {
     }
 }
 
-/// A power supply which doesn't have a "scope" attribute should be assumed to
-/// have "System" scope.
+/// Attribute value in the "available values" format, where all supported values
+/// are separated by spaces and the active one is enclosed in brackets
+/// (ex. `auto [inhibit-charge] force-discharge`).
+///
+/// Attribute without brackets is treated as a single active value.
+/// Values might contain spaces (ex. `Standard [Long Life] Fast`),
+/// values which are failed to parse are skipped.
 #[derive(Debug, Eq, PartialEq)]
-pub enum Scope {
-    /// Powers a specific device, or tree of devices
-    Device,
-    /// Powers the whole system
-    System,
-    /// Unknown power topology
-    Unknown,
-    __Nonexhaustive,
+pub struct Choices<V> {
+    pub active: Option<V>,
+    pub available: Vec<V>,
 }
 
-impl FromStr for Scope {
+impl<V: FromStr + Clone> FromStr for Choices<V> {
     type Err = io::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let value = match s {
-            _ if s.eq_ignore_ascii_case("Device") => Scope::Device,
-            _ if s.eq_ignore_ascii_case("System") => Scope::System,
-            _ if s.eq_ignore_ascii_case("Unknown") => Scope::Unknown,
-            _ => Scope::Unknown,
+        let mut choices = Choices {
+            active: None,
+            available: Vec::new(),
         };
+        let mut is_bracketed = false;
 
-        Ok(value)
+        let mut words = s.split_whitespace().peekable();
+        while let Some(word) = words.next() {
+            let mut item = word.to_string();
+            let is_active = item.starts_with('[');
+            if is_active {
+                while !item.ends_with(']') {
+                    match words.next() {
+                        Some(word) => {
+                            item.push(' ');
+                            item.push_str(word);
+                        }
+                        None => break,
+                    }
+                }
+                item = item
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .to_string();
+            } else if V::from_str(&item).is_err() {
+                // Unbracketed value with spaces is joined with the next word
+                if let Some(next) = words.peek().filter(|next| !next.starts_with('[')) {
+                    let joined = format!("{} {}", item, next);
+                    if V::from_str(&joined).is_ok() {
+                        item = joined;
+                        words.next();
+                    }
+                }
+            }
+            is_bracketed |= is_active;
+
+            if let Ok(value) = V::from_str(&item) {
+                if is_active {
+                    choices.active = Some(value.clone());
+                }
+                choices.available.push(value);
+            }
+        }
+
+        if !is_bracketed && choices.available.len() == 1 {
+            choices.active = choices.available.first().cloned();
+        }
+
+        Ok(choices)
+    }
+}
+
+impl Type {
+    /// Returns `None` for batteries and unknown types.
+    pub fn adapter_type(&self) -> Option<AdapterType> {
+        match self {
+            Type::Mains => Some(AdapterType::Mains),
+            Type::Ups => Some(AdapterType::Ups),
+            Type::Usb => Some(AdapterType::Usb),
+            _ => None,
+        }
     }
 }
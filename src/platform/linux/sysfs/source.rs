@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::f32;
 use std::io;
 use std::path::Path;
@@ -5,32 +6,61 @@ use std::path::Path;
 use lazycell::LazyCell;
 use num_traits::identities::Zero;
 
-use super::fs;
-use crate::units::power::{microwatt, watt};
+use super::super::adapter::SysFsAdapterIterator;
+use super::{Attributes, Choices, CHARGE_END_THRESHOLD, CHARGE_START_THRESHOLD, CHARGE_TYPE};
+use crate::units::power::microwatt;
 use crate::units::{
-    Bound, ElectricCharge, ElectricPotential, Energy, Power, Ratio, ThermodynamicTemperature,
+    Bound, ElectricCharge, ElectricCurrent, ElectricPotential, Energy, Power, Ratio,
+    ThermodynamicTemperature,
+};
+use crate::{
+    AdapterType, CapacityLevel, ChargeBehaviour, ChargeThresholds, ChargeType, DataProvenance,
+    DesignVoltageSource, DeviceKind, Error, Health, ManufactureDate, PowerFlow, Provenance, Result,
+    SanitizePolicy, Scope, State, Technology,
 };
-use crate::{Error, Result, State, Technology};
 
 #[derive(Debug)]
 pub struct InstantData {
     pub state_of_health: Ratio,
     pub state_of_charge: Ratio,
+    pub capacity_level: Option<CapacityLevel>,
+    pub capacity_error_margin: Option<Ratio>,
 
     pub energy: Energy,
     pub energy_full: Energy,
     pub energy_full_design: Energy,
     pub energy_rate: Power,
+    pub power_flow: PowerFlow,
     pub voltage: ElectricPotential,
     pub state: State,
+    pub health: Option<Health>,
     pub temperature: Option<ThermodynamicTemperature>,
     pub cycle_count: Option<u32>,
+    pub charge_thresholds: Option<ChargeThresholds>,
+    pub charge_behaviour: Option<Choices<ChargeBehaviour>>,
+    pub charge_type: Option<Choices<ChargeType>>,
+
+    // Native values, which are not converted into energy
+    pub current: Option<ElectricCurrent>,
+    pub charge: Option<ElectricCharge>,
+    pub charge_full: Option<ElectricCharge>,
+    pub charge_full_design: Option<ElectricCharge>,
+    pub charge_counter: Option<ElectricCharge>,
+
+    pub voltage_min_design: Option<ElectricPotential>,
+    pub voltage_max_design: Option<ElectricPotential>,
+    pub voltage_ocv: Option<ElectricPotential>,
+    pub voltage_avg: Option<ElectricPotential>,
+    pub design_voltage_source: Option<DesignVoltageSource>,
+
+    pub provenance: DataProvenance,
 }
 
-pub struct DataBuilder<'p> {
-    root: &'p Path,
+pub struct DataBuilder {
+    attrs: Attributes,
+    policy: SanitizePolicy,
 
-    design_voltage: LazyCell<ElectricPotential>,
+    design_voltage: LazyCell<(ElectricPotential, DesignVoltageSource)>,
     energy: LazyCell<Energy>,
     energy_full: LazyCell<Energy>,
     energy_full_design: LazyCell<Energy>,
@@ -40,12 +70,16 @@ pub struct DataBuilder<'p> {
     state_of_charge: LazyCell<Ratio>,
 
     state: LazyCell<State>,
+
+    // Filled as a side effect of the values calculation above
+    provenance: RefCell<DataProvenance>,
 }
 
-impl<'p> DataBuilder<'p> {
-    pub fn new(path: &'p Path) -> DataBuilder<'p> {
+impl DataBuilder {
+    pub fn new(path: &Path, policy: SanitizePolicy) -> DataBuilder {
         DataBuilder {
-            root: path,
+            attrs: Attributes::new(path),
+            policy,
 
             design_voltage: LazyCell::new(),
             energy: LazyCell::new(),
@@ -55,43 +89,67 @@ impl<'p> DataBuilder<'p> {
             state_of_health: LazyCell::new(),
             state_of_charge: LazyCell::new(),
             state: LazyCell::new(),
+            provenance: RefCell::new(DataProvenance::default()),
         }
     }
 
     pub fn collect(self) -> Result<InstantData> {
         Ok(InstantData {
             state_of_charge: *self.state_of_charge()?,
+            capacity_level: self.capacity_level()?,
+            capacity_error_margin: self.capacity_error_margin()?,
             state_of_health: *self.state_of_health()?,
             energy: *self.energy()?,
             energy_full: *self.energy_full()?,
             energy_full_design: *self.energy_full_design()?,
             energy_rate: *self.energy_rate()?,
+            power_flow: self.power_flow()?,
             voltage: self.voltage()?,
             state: *self.state()?,
+            health: self.health()?,
             temperature: self.temperature()?,
             cycle_count: self.cycle_count()?,
+            charge_thresholds: self.charge_thresholds()?,
+            charge_behaviour: self.charge_behaviour()?,
+            charge_type: self.charge_type()?,
+            current: self.current()?,
+            charge: self.charge_now(),
+            charge_full: self.attrs.charge("charge_full")?,
+            charge_full_design: self.attrs.charge("charge_full_design")?,
+            charge_counter: self.attrs.charge("charge_counter")?,
+            voltage_min_design: self.attrs.voltage("voltage_min_design")?,
+            voltage_max_design: self.attrs.voltage("voltage_max_design")?,
+            voltage_ocv: self.attrs.voltage("voltage_ocv")?,
+            voltage_avg: self.attrs.voltage("voltage_avg")?,
+            // Design voltage is used only for the devices reporting charge values,
+            // so it should be checked after all the energy values were calculated
+            design_voltage_source: self.design_voltage.borrow().map(|(_, source)| *source),
+            // Should be the last one, after all the values were calculated
+            provenance: *self.provenance.borrow(),
         })
     }
 
-    fn design_voltage(&self) -> Result<&ElectricPotential> {
-        self.design_voltage.try_borrow_with(|| {
+    fn design_voltage(&self) -> Result<ElectricPotential> {
+        let (voltage, _) = self.design_voltage.try_borrow_with(|| -> Result<_> {
             let value = [
-                "voltage_max_design",
-                "voltage_min_design",
-                "voltage_present",
-                "voltage_now",
+                ("voltage_max_design", DesignVoltageSource::MaxDesign),
+                ("voltage_min_design", DesignVoltageSource::MinDesign),
+                ("voltage_present", DesignVoltageSource::Present),
+                ("voltage_now", DesignVoltageSource::Now),
             ]
             .iter()
-            .filter_map(|filename| match fs::voltage(self.root.join(filename)) {
-                Ok(Some(value)) => Some(value),
+            .filter_map(|(filename, source)| match self.attrs.voltage(filename) {
+                Ok(Some(value)) => Some((value, *source)),
                 _ => None,
             })
             .next();
             match value {
-                Some(voltage) => Ok(voltage),
+                Some(value) => Ok(value),
                 None => Err(io::Error::from(io::ErrorKind::NotFound).into()),
             }
-        })
+        })?;
+
+        Ok(*voltage)
     }
 
     // Not cached because used only once
@@ -99,7 +157,7 @@ impl<'p> DataBuilder<'p> {
     fn energy_now(&self) -> Option<Energy> {
         ["energy_now", "energy_avg"]
             .iter()
-            .filter_map(|filename| match fs::energy(self.root.join(filename)) {
+            .filter_map(|filename| match self.attrs.energy(filename) {
                 Ok(Some(value)) => Some(value),
                 _ => None,
             })
@@ -111,7 +169,7 @@ impl<'p> DataBuilder<'p> {
     fn charge_now(&self) -> Option<ElectricCharge> {
         ["charge_now", "charge_avg"]
             .iter()
-            .filter_map(|filename| match fs::charge(self.root.join(filename)) {
+            .filter_map(|filename| match self.attrs.charge(filename) {
                 Ok(Some(value)) => Some(value),
                 _ => None,
             })
@@ -122,7 +180,7 @@ impl<'p> DataBuilder<'p> {
     fn charge_full(&self) -> ElectricCharge {
         ["charge_full", "charge_full_design"]
             .iter()
-            .filter_map(|filename| match fs::charge(self.root.join(filename)) {
+            .filter_map(|filename| match self.attrs.charge(filename) {
                 Ok(Some(value)) => Some(value),
                 _ => None,
             })
@@ -135,75 +193,117 @@ impl<'p> DataBuilder<'p> {
             let energy_full = self.energy_full()?;
             if !energy_full.is_zero() {
                 let energy_full_design = self.energy_full_design()?;
+                let mut provenance = self.provenance.borrow_mut();
+                provenance.state_of_health = provenance
+                    .energy_full
+                    .combine(provenance.energy_full_design);
                 Ok((*energy_full / *energy_full_design).into_bounded())
             } else {
+                self.provenance.borrow_mut().state_of_health = Provenance::Defaulted;
                 Ok(percent!(100.0))
             }
         })
     }
 
     fn energy(&self) -> Result<&Energy> {
-        self.energy.try_borrow_with(|| match self.energy_now() {
-            Some(energy) => Ok(energy),
-            None => match self.charge_now() {
-                Some(charge) => Ok(charge * *self.design_voltage()?),
-                None => match fs::get::<f32, _>(self.root.join("capacity")) {
-                    Ok(Some(capacity)) => {
-                        Ok(*self.energy_full()? * percent!(capacity).into_bounded())
+        self.energy.try_borrow_with(|| {
+            let (value, provenance) = match self.energy_now() {
+                Some(energy) => (energy, Provenance::Measured),
+                None => match self.charge_now() {
+                    Some(charge) => (
+                        charge * self.design_voltage()?,
+                        Provenance::DerivedFromCharge,
+                    ),
+                    None => {
+                        let soc = match self.attrs.get::<f32>("capacity") {
+                            Ok(Some(capacity)) => percent!(capacity).into_bounded(),
+                            _ => match self.capacity_level_soc()? {
+                                Some(soc) => soc,
+                                None => {
+                                    return Err(Error::not_found(
+                                        "Unable to calculate device energy value",
+                                    ))
+                                }
+                            },
+                        };
+                        let energy_full = *self.energy_full()?;
+                        let provenance = Provenance::DerivedFromCapacity
+                            .combine(self.provenance.borrow().energy_full);
+                        (energy_full * soc, provenance)
                     }
-                    _ => Err(Error::not_found("Unable to calculate device energy value")),
                 },
-            },
+            };
+            self.provenance.borrow_mut().energy = provenance;
+
+            Ok(value)
         })
     }
 
     fn energy_full(&self) -> Result<&Energy> {
-        self.energy_full
-            .try_borrow_with(|| match fs::energy(self.root.join("energy_full")) {
-                Ok(Some(value)) => Ok(value),
-                Ok(None) => match fs::charge(self.root.join("charge_full")) {
-                    Ok(Some(value)) => Ok(value * *self.design_voltage()?),
-                    Ok(None) => Ok(*self.energy_full_design()?),
-                    Err(e) => Err(e),
+        self.energy_full.try_borrow_with(|| {
+            let (value, provenance) = match self.attrs.energy("energy_full")? {
+                Some(value) => (value, Provenance::Measured),
+                None => match self.attrs.charge("charge_full")? {
+                    Some(value) => (
+                        value * self.design_voltage()?,
+                        Provenance::DerivedFromCharge,
+                    ),
+                    // Last full capacity is unknown, assuming it is the same as the design one
+                    None => (*self.energy_full_design()?, Provenance::Defaulted),
                 },
-                Err(e) => Err(e),
-            })
+            };
+            self.provenance.borrow_mut().energy_full = provenance;
+
+            Ok(value)
+        })
     }
 
     fn energy_full_design(&self) -> Result<&Energy> {
         self.energy_full_design.try_borrow_with(|| {
-            match fs::energy(self.root.join("energy_full_design")) {
-                Ok(Some(value)) => Ok(value),
-                Ok(None) => match fs::charge(self.root.join("charge_full_design")) {
-                    Ok(Some(value)) => Ok(value * *self.design_voltage()?),
+            let (value, provenance) = match self.attrs.energy("energy_full_design")? {
+                Some(value) => (value, Provenance::Measured),
+                None => match self.attrs.charge("charge_full_design")? {
+                    Some(value) => (
+                        value * self.design_voltage()?,
+                        Provenance::DerivedFromCharge,
+                    ),
                     // It is possible that both `energy_full_design` and `charge_full_design`
                     // files might be missing, see #40.
                     // As a workaround, doing the same what `upower` does - falling back to zero value
                     // It will affect other parameters calculation,
                     // and in a future versions this function probably should return
                     // `Result<Option<Energy>>` instead to mark missing value.
-                    Ok(None) => Ok(microwatt_hour!(0.0)),
-                    Err(e) => Err(e),
+                    None => (microwatt_hour!(0.0), Provenance::Defaulted),
                 },
-                Err(e) => Err(e),
-            }
+            };
+            self.provenance.borrow_mut().energy_full_design = provenance;
+
+            Ok(value)
         })
     }
 
     fn energy_rate(&self) -> Result<&Power> {
         self.energy_rate.try_borrow_with(|| {
-            let value = match fs::power(self.root.join("power_now"))? {
+            let power_now = self
+                .attrs
+                .power("power_now")?
+                .filter(|power| *power > self.policy.min_power);
+            let mut provenance = Provenance::Measured;
+            let value = match power_now {
                 Some(power) => Some(power),
                 None => {
-                    match fs::get::<f32, _>(self.root.join("current_now"))? {
+                    match self.attrs.get::<f32>("current_now")? {
                         Some(current_now) => {
+                            // Sign is handled by the `power_flow`
+                            let current_now = current_now.abs();
                             // If charge_full exists, then current_now is always reported in µA.
                             // In the legacy case, where energy only units exist, and power_now isn't present
                             // current_now is power in µW.
                             // Source: upower
                             if !self.charge_full().is_zero() {
                                 // µA then
-                                Some(microampere!(current_now) * *self.design_voltage()?)
+                                provenance = Provenance::DerivedFromCharge;
+                                Some(microampere!(current_now) * self.design_voltage()?)
                             } else {
                                 // µW :|
                                 Some(microwatt!(current_now))
@@ -214,15 +314,8 @@ impl<'p> DataBuilder<'p> {
                 }
             };
 
+            let raw = value;
             let value = value
-                // Sanity check if power is greater than 100W (upower)
-                .map(|power| {
-                    if power.get::<watt>() > 100.0 {
-                        watt!(0.0)
-                    } else {
-                        power
-                    }
-                })
                 // Some batteries give out massive rate values when nearly empty (upower)
                 .map(|power| {
                     if power.get::<microwatt>() < 10.0 {
@@ -231,72 +324,164 @@ impl<'p> DataBuilder<'p> {
                         power
                     }
                 })
-                // ACPI gives out the special 'Ones' (Constant Ones Object) value for rate
-                // when it's unable to calculate the true rate. We should set the rate zero,
-                // and wait for the BIOS to stabilise.
-                // Source: upower
-                //
-                // It come as an `0xffff` originally, but we are operating with `Power` now,
-                // so this `Ones` value is recalculated a little.
-                .map(|power| {
-                    // TODO: There might be a chance that we had lost a precision during the conversion
-                    // from the microwatts into default watts, so this should be fixed
-                    if (power.get::<watt>() - 65535.0).abs() < f32::EPSILON {
-                        watt!(0.0)
-                    } else {
-                        power
-                    }
-                })
+                // Sanity checks for the too high values and the ACPI 'Ones' value
+                .map(|power| self.policy.energy_rate(power))
                 .unwrap_or_else(|| microwatt!(0.0));
 
-            // TODO: Calculate energy_rate manually, if hardware fails.
-            // if value < 0.01 {
-            //    // Check upower `up_device_supply_calculate_rate` function
-            // }
+            // If hardware fails, `SysFsDevice` calculates the rate from the energy changes
+            // between refreshes, see `RateSampler`.
+            match raw {
+                Some(raw) if value.is_zero() && !raw.is_zero() => {
+                    provenance = Provenance::Defaulted
+                }
+                None => provenance = Provenance::Defaulted,
+                _ => {}
+            }
+            self.provenance.borrow_mut().energy_rate = provenance;
 
             Ok(value)
         })
     }
 
+    fn power_flow(&self) -> Result<PowerFlow> {
+        let rate = *self.energy_rate()?;
+        let state = *self.state()?;
+        match state {
+            _ if rate.is_zero() => Ok(PowerFlow::Idle),
+            State::Charging | State::Discharging => Ok(PowerFlow::new(state, rate)),
+            _ => {
+                // Drivers reporting signed values are using negative ones for discharging,
+                // which is the only way to know the direction if state is not reported.
+                // Positive values are not telling anything, since most of the drivers
+                // are reporting unsigned values in both directions
+                let value = match self.attrs.get::<f32>("power_now")? {
+                    Some(value) => Some(value),
+                    None => self.attrs.get::<f32>("current_now")?,
+                };
+                match value {
+                    Some(value) if value < 0.0 => Ok(PowerFlow::Discharging(rate)),
+                    // System battery with unknown state is charging from the online adapter
+                    // and powering the system otherwise.
+                    // Peripherals are not powered by the system adapters
+                    _ if state == State::Unknown && self.scope()? == Scope::System => {
+                        match self.external_power() {
+                            Some(true) => Ok(PowerFlow::Charging(rate)),
+                            Some(false) => Ok(PowerFlow::Discharging(rate)),
+                            None => Ok(PowerFlow::Idle),
+                        }
+                    }
+                    _ => Ok(PowerFlow::Idle),
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if any of the AC or USB adapters next to the device is online,
+    /// `false` if all of them are offline, or `None` if there are no adapters reporting it.
+    // IO errors are ignored, since adapters are used as a hint only
+    fn external_power(&self) -> Option<bool> {
+        let root = self.attrs.path().parent()?;
+
+        SysFsAdapterIterator::new(root)
+            .ok()?
+            .filter_map(|adapter| adapter.ok())
+            .filter(|adapter| matches!(adapter.type_(), AdapterType::Mains | AdapterType::Usb))
+            .filter_map(|adapter| adapter.online())
+            .reduce(|online, other| online || other)
+    }
+
     fn state_of_charge(&self) -> Result<&Ratio> {
         self.state_of_charge.try_borrow_with(|| {
-            match fs::get::<f32, _>(self.root.join("capacity")) {
-                Ok(Some(capacity)) => Ok(percent!(capacity).into_bounded()),
-                Ok(None) if self.energy_full()?.is_sign_positive() => {
-                    Ok(*self.energy()? / *self.energy_full()?)
+            let (value, provenance) = match self.attrs.get::<f32>("capacity")? {
+                Some(capacity) => (percent!(capacity).into_bounded(), Provenance::Measured),
+                None if self.energy_full()?.is_sign_positive()
+                    && !self.energy_full()?.is_zero() =>
+                {
+                    let value = *self.energy()? / *self.energy_full()?;
+                    let provenance = self.provenance.borrow();
+                    (value, provenance.energy.combine(provenance.energy_full))
                 }
-                // Same as upower, falling back to 0.0%
-                Ok(None) => Ok(percent!(0.0)),
-                Err(e) => Err(e),
-            }
+                None => match self.capacity_level_soc()? {
+                    Some(soc) => (soc, Provenance::DerivedFromCapacity),
+                    // Same as upower, falling back to 0.0%
+                    None => (percent!(0.0), Provenance::Defaulted),
+                },
+            };
+            self.provenance.borrow_mut().state_of_charge = provenance;
+
+            Ok(value)
         })
     }
 
     fn state(&self) -> Result<&State> {
         self.state
-            .try_borrow_with(|| match fs::get::<State, _>(self.root.join("status")) {
+            .try_borrow_with(|| match self.attrs.get::<State>("status") {
                 Ok(Some(state)) => Ok(state),
                 Ok(None) => Ok(State::Unknown),
                 Err(e) => Err(e),
             })
     }
 
+    fn capacity_level(&self) -> Result<Option<CapacityLevel>> {
+        self.attrs.get::<CapacityLevel>("capacity_level")
+    }
+
+    fn capacity_error_margin(&self) -> Result<Option<Ratio>> {
+        Ok(self
+            .attrs
+            .get::<f32>("capacity_error_margin")?
+            .map(|value| percent!(value).into_bounded()))
+    }
+
+    // Representative state of charge for drivers,
+    // which are providing neither `capacity`, nor energy or charge values
+    fn capacity_level_soc(&self) -> Result<Option<Ratio>> {
+        Ok(self
+            .capacity_level()?
+            .and_then(|level| level.state_of_charge()))
+    }
+
+    fn health(&self) -> Result<Option<Health>> {
+        self.attrs.get::<Health>("health")
+    }
+
     fn voltage(&self) -> Result<ElectricPotential> {
         let mut value = ["voltage_now", "voltage_avg"]
             .iter()
-            .filter_map(|filename| match fs::voltage(self.root.join(filename)) {
+            .filter_map(|filename| match self.attrs.voltage(filename) {
                 Ok(Some(value)) => Some(value),
                 _ => None,
             });
 
         match value.next() {
             Some(value) => Ok(value),
-            None => Err(Error::not_found("Unable to calculate device voltage value")),
+            // Peripheral batteries (HID ones, for example) are usually reporting
+            // the capacity and status only, same goes for some system batteries,
+            // which should not fail the whole device
+            None => {
+                self.provenance.borrow_mut().voltage = Provenance::Defaulted;
+                Ok(volt!(0.0))
+            }
         }
     }
 
+    /// Same as for the `energy_rate`, legacy drivers with energy units only are reporting
+    /// `current_now` in µW, so it is ignored if there is no evidence that it is in µA.
+    fn current(&self) -> Result<Option<ElectricCurrent>> {
+        let value = match self.attrs.get::<f32>("current_now")? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        if self.charge_full().is_zero() && self.attrs.get::<f32>("power_now")?.is_none() {
+            return Ok(None);
+        }
+
+        // Some drivers are reporting negative values while discharging
+        Ok(Some(microampere!(value.abs())))
+    }
+
     fn temperature(&self) -> Result<Option<ThermodynamicTemperature>> {
-        match fs::get::<f32, _>(self.root.join("temp")) {
+        match self.attrs.get::<f32>("temp") {
             Ok(Some(value)) => Ok(Some(celsius!(value / 10.0))),
             Ok(None) => Ok(None),
             Err(e) => Err(e),
@@ -304,7 +489,7 @@ impl<'p> DataBuilder<'p> {
     }
 
     fn cycle_count(&self) -> Result<Option<u32>> {
-        fs::get::<u32, _>(self.root.join("cycle_count")).map(|value| {
+        self.attrs.get::<u32>("cycle_count").map(|value| {
             // Handling zero cycles count as a non-existing value.
             // Reason: some drivers are creating `cycle_count` with zero value
             // even for old batteries.
@@ -319,25 +504,158 @@ impl<'p> DataBuilder<'p> {
         })
     }
 
+    pub fn charge_thresholds(&self) -> Result<Option<ChargeThresholds>> {
+        let start = self.charge_threshold(&CHARGE_START_THRESHOLD)?;
+        let end = self.charge_threshold(&CHARGE_END_THRESHOLD)?;
+
+        match (start, end) {
+            (None, None) => Ok(None),
+            (start, end) => Ok(Some(ChargeThresholds { start, end })),
+        }
+    }
+
+    fn charge_threshold(&self, filenames: &[&str]) -> Result<Option<Ratio>> {
+        for filename in filenames {
+            if let Some(value) = self.attrs.get::<f32>(filename)? {
+                return Ok(Some(percent!(value).into_bounded()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn charge_behaviour(&self) -> Result<Option<Choices<ChargeBehaviour>>> {
+        self.attrs.choices("charge_behaviour")
+    }
+
+    fn charge_type(&self) -> Result<Option<Choices<ChargeType>>> {
+        for filename in CHARGE_TYPE {
+            if let Some(choices) = self.attrs.choices(filename)? {
+                return Ok(Some(choices));
+            }
+        }
+
+        Ok(None)
+    }
+
     // Following methods are not cached in the struct
 
     pub fn manufacturer(&self) -> Result<Option<String>> {
-        fs::get_string(self.root.join("manufacturer"))
+        self.attrs.get_string("manufacturer")
     }
 
     pub fn model(&self) -> Result<Option<String>> {
-        fs::get_string(self.root.join("model_name"))
+        self.attrs.get_string("model_name")
     }
 
     pub fn serial_number(&self) -> Result<Option<String>> {
-        fs::get_string(self.root.join("serial_number"))
+        self.attrs.get_string("serial_number")
+    }
+
+    pub fn manufacture_date(&self) -> Result<Option<ManufactureDate>> {
+        let year = match self.attrs.get::<u16>("manufacture_year")? {
+            Some(year) => year,
+            None => return Ok(None),
+        };
+        let month = self.attrs.get::<u8>("manufacture_month")?;
+        let day = self.attrs.get::<u8>("manufacture_day")?;
+
+        Ok(ManufactureDate::new(year, month, day))
+    }
+
+    /// Raw `technology` attribute value, as it was reported by the driver.
+    pub fn battery_type(&self) -> Result<Option<String>> {
+        self.attrs.get_string("technology")
     }
 
     pub fn technology(&self) -> Result<Technology> {
-        match fs::get::<Technology, _>(self.root.join("technology")) {
+        match self.attrs.get::<Technology>("technology") {
             Ok(Some(tech)) => Ok(tech),
             Ok(None) => Ok(Technology::Unknown),
             Err(e) => Err(e),
         }
     }
+
+    pub fn scope(&self) -> Result<Scope> {
+        match self.attrs.get::<Scope>("scope") {
+            Ok(Some(scope)) => Ok(scope),
+            // A power supply which doesn't have a "scope" attribute
+            // should be assumed to have "System" scope
+            Ok(None) => Ok(Scope::System),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Guessing the peripheral device kind from the power supply name (ex. `hidpp_battery_0`),
+    /// its `model_name` and the parent device.
+    ///
+    /// Parent device is checked by its name first and then by the input handlers
+    /// it had registered, since the names are often not descriptive at all
+    /// (ex. `0003:046D:4082.0004`).
+    pub fn device_kind(&self, model: Option<&str>) -> DeviceKind {
+        let parent = std::fs::read_link(self.attrs.path().join("device")).ok();
+        let hints = [
+            self.attrs.path().file_name().and_then(|name| name.to_str()),
+            model,
+            parent
+                .as_ref()
+                .and_then(|path| path.file_name())
+                .and_then(|name| name.to_str()),
+        ];
+
+        hints
+            .iter()
+            .flatten()
+            .find_map(|hint| device_kind_from_hint(hint))
+            .or_else(|| self.device_kind_from_input())
+            .unwrap_or_default()
+    }
+
+    fn device_kind_from_input(&self) -> Option<DeviceKind> {
+        let inputs = std::fs::read_dir(self.attrs.path().join("device").join("input")).ok()?;
+        for input in inputs.flatten() {
+            let handlers = match std::fs::read_dir(input.path()) {
+                Ok(handlers) => handlers,
+                Err(_) => continue,
+            };
+            for handler in handlers.flatten() {
+                let name = handler.file_name();
+                let name = name.to_string_lossy();
+                if name.starts_with("mouse") {
+                    return Some(DeviceKind::Mouse);
+                } else if name.starts_with("js") {
+                    return Some(DeviceKind::Gamepad);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+fn device_kind_from_hint(hint: &str) -> Option<DeviceKind> {
+    let hint = hint.to_ascii_lowercase();
+    let contains = |needles: &[&str]| needles.iter().any(|needle| hint.contains(needle));
+
+    // Order matters: "headphone" should not be matched as a phone
+    match () {
+        _ if contains(&["headset", "headphone", "earbud", "airpods"]) => Some(DeviceKind::Headset),
+        _ if contains(&[
+            "controller",
+            "gamepad",
+            "joystick",
+            "joy-con",
+            "dualshock",
+            "dualsense",
+            "xbox",
+            "wiimote",
+        ]) =>
+        {
+            Some(DeviceKind::Gamepad)
+        }
+        _ if contains(&["keyboard", "kbd"]) => Some(DeviceKind::Keyboard),
+        _ if contains(&["mouse", "trackball"]) => Some(DeviceKind::Mouse),
+        _ if contains(&["phone"]) => Some(DeviceKind::Phone),
+        _ => None,
+    }
 }
@@ -0,0 +1,50 @@
+use approx::assert_abs_diff_eq;
+
+use crate::{AdapterType, Manager, UsbType};
+
+#[test]
+fn test_adapters() -> std::io::Result<()> {
+    let root = tempfile::tempdir()?;
+    sysfs_test_device!(root.path(), "AC",
+        "type" => "Mains",
+        "online" => 0
+    );
+    sysfs_test_device!(root.path(), "ucsi-source-psy-USBC000:001",
+        "type" => "USB",
+        "online" => 1,
+        "usb_type" => "C [PD] PD_PPS",
+        "voltage_max" => 20000000,
+        "current_max" => 3250000,
+        "voltage_min" => 5000000
+    );
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "capacity" => 50
+    );
+
+    let manager = Manager::with_root(root.path()).unwrap();
+    let mut adapters = manager
+        .adapters()
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+    adapters.sort_by(|a, b| a.name().cmp(b.name()));
+
+    assert_eq!(adapters.len(), 2);
+
+    assert_eq!(adapters[0].name(), "AC");
+    assert_eq!(adapters[0].type_(), AdapterType::Mains);
+    assert_eq!(adapters[0].online(), Some(false));
+    assert_eq!(adapters[0].usb_type(), None);
+    assert!(adapters[0].voltage_max().is_none());
+    assert!(adapters[0].current_max().is_none());
+
+    assert_eq!(adapters[1].type_(), AdapterType::Usb);
+    assert_eq!(adapters[1].online(), Some(true));
+    assert_eq!(adapters[1].usb_type(), Some(UsbType::Pd));
+    assert_abs_diff_eq!(adapters[1].voltage_max().unwrap().value, 20.0);
+    assert_abs_diff_eq!(adapters[1].current_max().unwrap().value, 3.25);
+    assert!(adapters[1].input_current_limit().is_none());
+
+    root.close()
+}
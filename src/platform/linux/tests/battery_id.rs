@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::fs;
+
+use crate::{BatteryId, Manager};
+
+#[test]
+fn test_battery_id() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 70,
+        "serial_number" => " 1234 "
+    );
+    sysfs_test_device!(root.path(), "BAT1",
+        "type" => "Battery",
+        "status" => "Charging",
+        "capacity" => 40
+    );
+
+    let manager = Manager::builder().sysfs_root(root.path()).build().unwrap();
+    let batteries = manager
+        .batteries()
+        .unwrap()
+        .map(|battery| {
+            let battery = battery.unwrap();
+            (battery.id(), battery)
+        })
+        .collect::<HashMap<_, _>>();
+    assert_eq!(batteries.len(), 2);
+
+    let id = batteries.keys().find(|id| id.name() == "BAT0").unwrap();
+    assert_eq!(id.serial_number(), Some("1234"));
+    assert_eq!(id.to_string(), "BAT0 (1234)");
+
+    let id = batteries.keys().find(|id| id.name() == "BAT1").unwrap();
+    assert_eq!(id.serial_number(), None);
+    assert_eq!(id.to_string(), "BAT1");
+
+    let battery = manager.battery(id).unwrap().unwrap();
+    assert_eq!(battery.id(), *id);
+    assert_eq!(battery.state(), batteries[id].state());
+}
+
+#[test]
+fn test_missing_battery() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 70,
+        "serial_number" => "1234"
+    );
+
+    let manager = Manager::builder().sysfs_root(root.path()).build().unwrap();
+    let mut id = manager.batteries().unwrap().next().unwrap().unwrap().id();
+
+    // Same device name, but the battery was swapped
+    id.serial_number = Some("5678".to_string());
+    assert!(manager.battery(&id).unwrap().is_none());
+}
+
+#[test]
+fn test_lookup_with_broken_battery() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 70
+    );
+    // Unreadable power supply, which fails the enumeration
+    fs::create_dir_all(root.path().join("BAT1").join("type")).unwrap();
+
+    let manager = Manager::builder().sysfs_root(root.path()).build().unwrap();
+    assert!(manager.batteries().unwrap().any(|battery| battery.is_err()));
+
+    let id = BatteryId::new("BAT0".to_string(), None);
+    let battery = manager.battery(&id).unwrap().unwrap();
+    assert_eq!(battery.id(), id);
+
+    for name in ["BAT2", "", "..", "../BAT0"] {
+        let id = BatteryId::new(name.to_string(), None);
+        assert!(manager.battery(&id).unwrap().is_none());
+    }
+}
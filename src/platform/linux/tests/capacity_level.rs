@@ -0,0 +1,42 @@
+use approx::assert_abs_diff_eq;
+
+use super::super::SysFsDevice;
+use crate::platform::traits::BatteryDevice;
+use crate::CapacityLevel;
+
+// Driver reports neither `capacity`, nor energy or charge values
+#[test]
+fn test_capacity_level_only() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity_level" => "Low",
+        "voltage_now" => 3800000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_eq!(device.capacity_level(), Some(CapacityLevel::Low));
+    assert_abs_diff_eq!(device.state_of_charge().value, 0.1);
+    assert_abs_diff_eq!(device.energy().value, 0.0);
+
+    root.close()
+}
+
+#[test]
+fn test_capacity_preferred_over_level() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 42,
+        "capacity_level" => "Normal",
+        "voltage_now" => 3800000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_eq!(device.capacity_level(), Some(CapacityLevel::Normal));
+    assert_abs_diff_eq!(device.state_of_charge().value, 0.42);
+
+    root.close()
+}
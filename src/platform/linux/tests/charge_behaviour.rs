@@ -0,0 +1,66 @@
+use std::io;
+use std::str::FromStr;
+
+use super::super::sysfs::Choices;
+use crate::{ChargeBehaviour, Manager, UsbType};
+
+#[test]
+fn test_choices() {
+    let choices = Choices::<ChargeBehaviour>::from_str("auto [inhibit-charge] force-discharge");
+    assert_eq!(
+        choices.unwrap(),
+        Choices {
+            active: Some(ChargeBehaviour::InhibitCharge),
+            available: vec![
+                ChargeBehaviour::Auto,
+                ChargeBehaviour::InhibitCharge,
+                ChargeBehaviour::ForceDischarge,
+            ],
+        }
+    );
+
+    // Unknown values are skipped
+    let choices = Choices::<ChargeBehaviour>::from_str("[auto] some-new-mode").unwrap();
+    assert_eq!(choices.active, Some(ChargeBehaviour::Auto));
+    assert_eq!(choices.available, vec![ChargeBehaviour::Auto]);
+
+    // Single value without brackets is the active one
+    let choices = Choices::<UsbType>::from_str("PD").unwrap();
+    assert_eq!(choices.active, Some(UsbType::Pd));
+
+    let choices = Choices::<UsbType>::from_str("C PD").unwrap();
+    assert_eq!(choices.active, None);
+    assert_eq!(choices.available, vec![UsbType::C, UsbType::Pd]);
+}
+
+#[test]
+fn test_charge_behaviour() -> io::Result<()> {
+    let root = tempfile::tempdir()?;
+    let path = sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Charging",
+        "capacity" => 50,
+        "voltage_now" => 12000000,
+        "charge_behaviour" => "[auto] inhibit-charge force-discharge"
+    );
+
+    let manager = Manager::with_root(root.path()).unwrap();
+    let battery = manager.batteries().unwrap().next().unwrap().unwrap();
+    assert_eq!(battery.charge_behaviour(), Some(ChargeBehaviour::Auto));
+    assert_eq!(battery.available_charge_behaviours().len(), 3);
+
+    manager
+        .set_charge_behaviour(&battery, ChargeBehaviour::ForceDischarge)
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(path.join("charge_behaviour"))?,
+        "force-discharge"
+    );
+
+    let e = manager
+        .set_charge_behaviour(&battery, ChargeBehaviour::InhibitChargeAwake)
+        .unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::Unsupported);
+
+    root.close()
+}
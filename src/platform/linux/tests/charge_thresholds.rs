@@ -0,0 +1,91 @@
+use std::io;
+
+use approx::assert_abs_diff_eq;
+
+use crate::units::ratio::percent;
+use crate::units::Ratio;
+use crate::Manager;
+
+fn battery(manager: &Manager) -> crate::Battery {
+    manager.batteries().unwrap().next().unwrap().unwrap()
+}
+
+#[test]
+fn test_charge_thresholds() -> io::Result<()> {
+    let root = tempfile::tempdir()?;
+    let path = sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Not charging",
+        "capacity" => 79,
+        "voltage_now" => 12000000,
+        "charge_control_start_threshold" => 40,
+        "charge_control_end_threshold" => 60
+    );
+
+    let manager = Manager::with_root(root.path()).unwrap();
+    let mut battery = battery(&manager);
+    let thresholds = battery.charge_thresholds().unwrap();
+    assert_abs_diff_eq!(thresholds.start().unwrap().value, 0.4);
+    assert_abs_diff_eq!(thresholds.end().unwrap().value, 0.6);
+
+    manager
+        .set_charge_thresholds(
+            &battery,
+            Some(Ratio::new::<percent>(75.0)),
+            Some(Ratio::new::<percent>(80.0)),
+        )
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(path.join("charge_control_end_threshold"))?,
+        "80"
+    );
+
+    manager.refresh(&mut battery).unwrap();
+    let thresholds = battery.charge_thresholds().unwrap();
+    assert_abs_diff_eq!(thresholds.start().unwrap().value, 0.75);
+    assert_abs_diff_eq!(thresholds.end().unwrap().value, 0.8);
+
+    let e = manager
+        .set_charge_thresholds(
+            &battery,
+            Some(Ratio::new::<percent>(80.0)),
+            Some(Ratio::new::<percent>(60.0)),
+        )
+        .unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+
+    root.close()
+}
+
+#[test]
+fn test_legacy_charge_thresholds() -> io::Result<()> {
+    let root = tempfile::tempdir()?;
+    let path = sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "capacity" => 50,
+        "voltage_now" => 12000000,
+        "charge_stop_threshold" => 100
+    );
+
+    let manager = Manager::with_root(root.path()).unwrap();
+    let battery = battery(&manager);
+    let thresholds = battery.charge_thresholds().unwrap();
+    assert!(thresholds.start().is_none());
+    assert_abs_diff_eq!(thresholds.end().unwrap().value, 1.0);
+
+    manager
+        .set_charge_thresholds(&battery, None, Some(Ratio::new::<percent>(80.4)))
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(path.join("charge_stop_threshold"))?,
+        "80"
+    );
+
+    let e = manager
+        .set_charge_thresholds(&battery, Some(Ratio::new::<percent>(40.0)), None)
+        .unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::Unsupported);
+    assert!(!path.join("charge_control_start_threshold").exists());
+
+    root.close()
+}
@@ -0,0 +1,83 @@
+use std::io;
+use std::str::FromStr;
+
+use super::super::sysfs::Choices;
+use crate::{ChargeType, Manager};
+
+#[test]
+fn test_choices_with_spaces() {
+    let choices = Choices::<ChargeType>::from_str("Standard [Long Life] Fast").unwrap();
+    assert_eq!(choices.active, Some(ChargeType::LongLife));
+    assert_eq!(
+        choices.available,
+        vec![ChargeType::Standard, ChargeType::LongLife, ChargeType::Fast]
+    );
+
+    let choices = Choices::<ChargeType>::from_str("[Fast] Long Life Adaptive").unwrap();
+    assert_eq!(choices.active, Some(ChargeType::Fast));
+    assert_eq!(
+        choices.available,
+        vec![ChargeType::Fast, ChargeType::LongLife, ChargeType::Adaptive]
+    );
+
+    // Legacy `charge_type` attribute
+    let choices = Choices::<ChargeType>::from_str("Long Life").unwrap();
+    assert_eq!(choices.active, Some(ChargeType::LongLife));
+}
+
+#[test]
+fn test_charge_types() -> io::Result<()> {
+    let root = tempfile::tempdir()?;
+    let path = sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Charging",
+        "capacity" => 50,
+        "voltage_now" => 12000000,
+        "charge_type" => "Fast",
+        "charge_types" => "Standard [Fast] Adaptive Long Life"
+    );
+
+    let manager = Manager::with_root(root.path()).unwrap();
+    let battery = manager.batteries().unwrap().next().unwrap().unwrap();
+    assert_eq!(battery.charge_type(), Some(ChargeType::Fast));
+    assert_eq!(battery.available_charge_types().len(), 4);
+
+    manager
+        .set_charge_type(&battery, ChargeType::LongLife)
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(path.join("charge_types"))?,
+        "Long Life"
+    );
+
+    let e = manager
+        .set_charge_type(&battery, ChargeType::Trickle)
+        .unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::Unsupported);
+
+    root.close()
+}
+
+// Only the active value is reported, so it can't be changed
+#[test]
+fn test_legacy_charge_type() -> io::Result<()> {
+    let root = tempfile::tempdir()?;
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Charging",
+        "capacity" => 50,
+        "voltage_now" => 12000000,
+        "charge_type" => "Trickle"
+    );
+
+    let manager = Manager::with_root(root.path()).unwrap();
+    let battery = manager.batteries().unwrap().next().unwrap().unwrap();
+    assert_eq!(battery.charge_type(), Some(ChargeType::Trickle));
+
+    let e = manager
+        .set_charge_type(&battery, ChargeType::Fast)
+        .unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::Unsupported);
+
+    root.close()
+}
@@ -0,0 +1,123 @@
+use approx::assert_abs_diff_eq;
+
+use crate::units::energy::watt_hour;
+use crate::units::power::watt;
+use crate::units::ratio::percent;
+use crate::units::time::hour;
+use crate::{Manager, State};
+
+#[test]
+fn test_dual_battery() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Unknown",
+        "voltage_now" => 12000000,
+        "power_now" => 0,
+        "energy_now" => 20000000,
+        "energy_full" => 20000000,
+        "energy_full_design" => 24000000
+    );
+    sysfs_test_device!(root.path(), "BAT1",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "power_now" => 10000000,
+        "energy_now" => 10000000,
+        "energy_full" => 60000000,
+        "energy_full_design" => 66000000
+    );
+    sysfs_test_device!(root.path(), "hidpp_battery_0",
+        "type" => "Battery",
+        "scope" => "Device",
+        "status" => "Charging",
+        "capacity" => 55
+    );
+
+    let manager = Manager::builder()
+        .sysfs_root(root.path())
+        .include_peripherals(true)
+        .build()
+        .unwrap();
+    let composite = manager.composite().unwrap().unwrap();
+
+    assert_eq!(composite.batteries(), 2);
+    assert_eq!(composite.state(), State::Discharging);
+    assert_abs_diff_eq!(composite.energy().get::<watt_hour>(), 30.0);
+    assert_abs_diff_eq!(composite.energy_full().get::<watt_hour>(), 80.0);
+    assert_abs_diff_eq!(composite.energy_full_design().get::<watt_hour>(), 90.0);
+    assert_abs_diff_eq!(
+        composite.state_of_charge().get::<percent>(),
+        37.5,
+        epsilon = 0.01
+    );
+    assert_abs_diff_eq!(composite.energy_rate().get::<watt>(), 10.0, epsilon = 0.001);
+    assert_abs_diff_eq!(
+        composite.time_to_empty().unwrap().get::<hour>(),
+        3.0,
+        epsilon = 0.001
+    );
+    assert_eq!(composite.time_to_full(), None);
+}
+
+#[test]
+fn test_no_batteries() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "AC",
+        "type" => "Mains",
+        "online" => 1
+    );
+
+    let manager = Manager::with_root(root.path()).unwrap();
+
+    assert!(manager.composite().unwrap().is_none());
+}
+
+#[test]
+fn test_mixed_power_flow() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Charging",
+        "voltage_now" => 12000000,
+        "power_now" => 5000000,
+        "energy_now" => 10000000,
+        "energy_full" => 20000000
+    );
+    let bat1 = sysfs_test_device!(root.path(), "BAT1",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "power_now" => 15000000,
+        "energy_now" => 20000000,
+        "energy_full" => 30000000
+    );
+    // Unreadable power supply is skipped
+    std::fs::create_dir_all(root.path().join("BAT2").join("type")).unwrap();
+
+    let manager = Manager::with_root(root.path()).unwrap();
+    let composite = manager.composite().unwrap().unwrap();
+
+    // Net flow is discharging, even though one of the batteries is charging
+    assert_eq!(composite.batteries(), 2);
+    assert_eq!(composite.state(), State::Discharging);
+    assert_abs_diff_eq!(composite.energy_rate().get::<watt>(), 10.0, epsilon = 0.001);
+    assert_abs_diff_eq!(
+        composite.time_to_empty().unwrap().get::<hour>(),
+        3.0,
+        epsilon = 0.001
+    );
+    assert_eq!(composite.time_to_full(), None);
+
+    std::fs::write(bat1.join("power_now"), "1000000").unwrap();
+    let composite = manager.composite().unwrap().unwrap();
+
+    assert_eq!(composite.state(), State::Charging);
+    assert_abs_diff_eq!(composite.energy_rate().get::<watt>(), 4.0, epsilon = 0.001);
+    assert_abs_diff_eq!(
+        composite.time_to_full().unwrap().get::<hour>(),
+        5.0,
+        epsilon = 0.001
+    );
+    assert_eq!(composite.time_to_empty(), None);
+}
@@ -0,0 +1,43 @@
+use crate::{Manager, State};
+
+#[test]
+fn test_custom_root() -> std::io::Result<()> {
+    let root = tempfile::tempdir()?;
+    sysfs_test_device!(root.path(), "AC",
+        "type" => "Mains",
+        "online" => 1
+    );
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Charging",
+        "energy_now" => 30000000,
+        "energy_full" => 60000000,
+        "energy_full_design" => 60000000,
+        "power_now" => 15000000,
+        "voltage_now" => 12000000
+    );
+
+    let manager = Manager::with_root(root.path()).unwrap();
+    let batteries = manager
+        .batteries()
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(batteries.len(), 1);
+    assert_eq!(batteries[0].state(), State::Charging);
+    assert_eq!(batteries[0].state_of_charge().value, 0.5);
+
+    root.close()
+}
+
+#[test]
+fn test_missing_custom_root() {
+    let root = tempfile::tempdir().unwrap();
+    let manager = Manager::builder()
+        .sysfs_root(root.path().join("missing"))
+        .build()
+        .unwrap();
+
+    assert!(manager.batteries().is_err());
+}
@@ -0,0 +1,93 @@
+use std::time::{Duration, Instant};
+
+use approx::assert_abs_diff_eq;
+
+use super::super::rate::{RateSampler, MIN_INTERVAL};
+use super::super::SysFsDevice;
+use crate::platform::traits::BatteryDevice;
+use crate::units::power::watt;
+use crate::State;
+
+#[test]
+fn test_rate_from_energy_delta() {
+    let start = Instant::now();
+    let mut sampler = RateSampler::new(watt_hour!(50.0), State::Discharging, start);
+
+    // One watt-hour in six minutes
+    let rate = sampler.update(
+        watt_hour!(49.0),
+        State::Discharging,
+        start + Duration::from_secs(360),
+    );
+    assert_abs_diff_eq!(rate.unwrap().get::<watt>(), 10.0, epsilon = 0.01);
+
+    // Energy value is not updated yet by the fuel gauge, previous rate is kept
+    let rate = sampler.update(
+        watt_hour!(49.0),
+        State::Discharging,
+        start + Duration::from_secs(420),
+    );
+    assert_abs_diff_eq!(rate.unwrap().get::<watt>(), 10.0, epsilon = 0.01);
+}
+
+#[test]
+fn test_min_interval() {
+    let start = Instant::now();
+    let mut sampler = RateSampler::new(watt_hour!(50.0), State::Discharging, start);
+
+    let rate = sampler.update(
+        watt_hour!(49.99),
+        State::Discharging,
+        start + MIN_INTERVAL / 2,
+    );
+    assert_eq!(rate, None);
+
+    // First sample is kept, so the rate is calculated over the whole interval
+    let rate = sampler.update(
+        watt_hour!(49.98),
+        State::Discharging,
+        start + MIN_INTERVAL + Duration::from_secs(26),
+    );
+    assert_abs_diff_eq!(rate.unwrap().get::<watt>(), 2.0, epsilon = 0.01);
+}
+
+#[test]
+fn test_reset_on_state_change() {
+    let start = Instant::now();
+    let mut sampler = RateSampler::new(watt_hour!(50.0), State::Discharging, start);
+
+    let rate = sampler.update(
+        watt_hour!(49.0),
+        State::Discharging,
+        start + Duration::from_secs(360),
+    );
+    assert!(rate.is_some());
+
+    let rate = sampler.update(
+        watt_hour!(49.5),
+        State::Charging,
+        start + Duration::from_secs(720),
+    );
+    assert_eq!(rate, None);
+}
+
+#[test]
+fn test_refresh_with_no_rate() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "energy_now" => 30000000,
+        "energy_full" => 50000000
+    );
+
+    let mut device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+    assert_abs_diff_eq!(device.energy_rate().get::<watt>(), 0.0);
+    assert_eq!(device.time_to_empty(), None);
+
+    // Interval between refreshes is too short to calculate the rate
+    device.refresh().unwrap();
+    assert_abs_diff_eq!(device.energy_rate().get::<watt>(), 0.0);
+
+    root.close()
+}
@@ -0,0 +1,110 @@
+use std::time::{Duration, Instant};
+
+use approx::assert_abs_diff_eq;
+
+use crate::units::power::watt;
+use crate::units::time::hour;
+use crate::{Estimator, Manager};
+
+fn set(root: &std::path::Path, name: &str, value: &str) {
+    std::fs::write(root.join("BAT0").join(name), format!("{}\n", value)).unwrap();
+}
+
+fn fixture() -> (tempfile::TempDir, Manager, Estimator) {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "power_now" => 10000000,
+        "energy_now" => 30000000,
+        "energy_full" => 50000000
+    );
+    let manager = Manager::with_root(root.path()).unwrap();
+    let battery = manager.batteries().unwrap().next().unwrap().unwrap();
+    let estimator = Estimator::new(battery).window(Duration::from_secs(60));
+
+    (root, manager, estimator)
+}
+
+#[test]
+fn test_smoothing() {
+    let (root, manager, mut estimator) = fixture();
+    let start = Instant::now();
+
+    assert_abs_diff_eq!(estimator.energy_rate().unwrap().get::<watt>(), 10.0);
+    assert_abs_diff_eq!(
+        estimator.time_to_empty().unwrap().get::<hour>(),
+        3.0,
+        epsilon = 0.001
+    );
+
+    set(root.path(), "power_now", "20000000");
+    estimator
+        .refresh_at(&manager, start + Duration::from_secs(60))
+        .unwrap();
+
+    // 1 - e^-1 of the difference
+    let rate = estimator.energy_rate().unwrap().get::<watt>();
+    assert_abs_diff_eq!(rate, 16.32, epsilon = 0.01);
+    assert_abs_diff_eq!(estimator.battery().energy_rate().get::<watt>(), 20.0);
+}
+
+#[test]
+fn test_outliers() {
+    let (root, manager, mut estimator) = fixture();
+    let start = Instant::now();
+
+    set(root.path(), "power_now", "90000000");
+    for i in 1..3 {
+        estimator
+            .refresh_at(&manager, start + Duration::from_secs(5 * i))
+            .unwrap();
+        assert_abs_diff_eq!(estimator.energy_rate().unwrap().get::<watt>(), 10.0);
+    }
+
+    // Too many outliers in a row are becoming a new normal
+    estimator
+        .refresh_at(&manager, start + Duration::from_secs(15))
+        .unwrap();
+    assert_abs_diff_eq!(estimator.energy_rate().unwrap().get::<watt>(), 90.0);
+}
+
+#[test]
+fn test_reset_on_state_change() {
+    let (root, manager, mut estimator) = fixture();
+    let start = Instant::now();
+
+    set(root.path(), "status", "Charging");
+    set(root.path(), "power_now", "30000000");
+    estimator
+        .refresh_at(&manager, start + Duration::from_secs(5))
+        .unwrap();
+
+    assert_abs_diff_eq!(estimator.energy_rate().unwrap().get::<watt>(), 30.0);
+    assert_eq!(estimator.time_to_empty(), None);
+    assert!(estimator.time_to_full().is_some());
+
+    set(root.path(), "status", "Full");
+    estimator
+        .refresh_at(&manager, start + Duration::from_secs(10))
+        .unwrap();
+
+    assert_eq!(estimator.energy_rate(), None);
+}
+
+#[test]
+#[should_panic(expected = "Outlier ratio should be greater than 1.0")]
+fn test_invalid_outlier_ratio() {
+    let (_root, _manager, estimator) = fixture();
+
+    estimator.outlier_ratio(1.0);
+}
+
+#[test]
+#[should_panic(expected = "Outlier ratio should be greater than 1.0")]
+fn test_nan_outlier_ratio() {
+    let (_root, _manager, estimator) = fixture();
+
+    estimator.outlier_ratio(f32::NAN);
+}
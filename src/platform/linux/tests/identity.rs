@@ -0,0 +1,79 @@
+use super::super::SysFsDevice;
+use crate::platform::traits::BatteryDevice;
+use crate::Technology;
+
+#[test]
+fn test_identity() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 90,
+        "voltage_now" => 12000000,
+        "manufacturer" => "SMP",
+        "model_name" => "5B10W13930",
+        "serial_number" => "1234",
+        "technology" => "Li-poly",
+        "manufacture_year" => 2019,
+        "manufacture_month" => 3,
+        "manufacture_day" => 15
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+    let identity = device.identity();
+
+    assert_eq!(identity.vendor(), Some("SMP"));
+    assert_eq!(identity.model(), Some("5B10W13930"));
+    assert_eq!(identity.serial_number(), Some("1234"));
+    assert_eq!(identity.battery_type(), Some("Li-poly"));
+    assert_eq!(device.technology(), Technology::LithiumPolymer);
+
+    let date = device.manufacture_date().unwrap();
+    assert_eq!(date, identity.manufacture_date().unwrap());
+    assert_eq!(
+        (date.year(), date.month(), date.day()),
+        (2019, Some(3), Some(15))
+    );
+    assert_eq!(date.to_string(), "2019-03-15");
+
+    root.close()
+}
+
+// Firmware reports zeroes for the unknown date components
+#[test]
+fn test_manufacture_date_partial() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 90,
+        "voltage_now" => 12000000,
+        "manufacture_year" => 2021,
+        "manufacture_month" => 0,
+        "manufacture_day" => 0
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+    let date = device.manufacture_date().unwrap();
+
+    assert_eq!((date.year(), date.month(), date.day()), (2021, None, None));
+    assert_eq!(date.to_string(), "2021");
+
+    root.close()
+}
+
+#[test]
+fn test_manufacture_date_missing() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 90,
+        "voltage_now" => 12000000,
+        "manufacture_year" => 0
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_eq!(device.manufacture_date(), None);
+    assert_eq!(device.identity().manufacture_date(), None);
+
+    root.close()
+}
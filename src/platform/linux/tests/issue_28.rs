@@ -38,6 +38,7 @@ fn test_issue_28() -> std::io::Result<()> {
     let device = device.unwrap();
 
     assert_eq!(device.state(), State::Discharging);
+    assert!(device.health().is_none());
     assert_eq!(device.technology(), Technology::LithiumIon);
     assert!(device.temperature().is_none());
     assert_eq!(device.cycle_count(), None);
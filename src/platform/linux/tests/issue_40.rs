@@ -2,7 +2,7 @@ use approx::assert_abs_diff_eq;
 
 use super::super::SysFsDevice;
 use crate::platform::traits::BatteryDevice;
-use crate::{State, Technology};
+use crate::{Health, State, Technology};
 
 // https://github.com/svartalf/rust-battery/issues/40
 //
@@ -32,6 +32,7 @@ fn test_issue_40() -> std::io::Result<()> {
     let device = device.unwrap();
 
     assert_eq!(device.state(), State::Discharging);
+    assert_eq!(device.health(), Some(Health::Good));
     assert_eq!(device.technology(), Technology::LithiumIon);
     assert_eq!(device.cycle_count(), None);
     assert!(device.vendor().is_none());
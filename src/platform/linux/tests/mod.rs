@@ -19,5 +19,51 @@ macro_rules! sysfs_test_suite {
     }};
 }
 
+/// Same as the `sysfs_test_suite!` macro, but creates the `{device}` directory
+/// inside of the `root` directory, which is representing
+/// the `/sys/class/power_supply` directory itself.
+macro_rules! sysfs_test_device {
+    ( $root:expr, $device:expr, $( $name:expr => $value:expr ),* ) => {{
+        use ::std::io::Write;
+
+        let path = $root.join($device);
+        ::std::fs::create_dir(&path).unwrap();
+
+        $(
+            let mut file = ::std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(path.join($name))
+                .unwrap();
+            file.write_fmt(format_args!("{}\n", $value)).unwrap();
+        )*
+
+        path
+    }};
+}
+
+mod adapters;
+mod battery_id;
+mod capacity_level;
+mod charge_behaviour;
+mod charge_thresholds;
+mod charge_type;
+mod composite;
+mod custom_root;
+mod energy_rate;
+mod estimator;
+mod identity;
 mod issue_28;
 mod issue_40;
+mod monitor;
+mod native_charge;
+mod optional_values;
+mod peripherals;
+mod power_flow;
+mod provenance;
+mod raw_properties;
+mod refresh_all;
+mod sanitize_policy;
+mod snapshot;
+mod uevent;
+mod voltage;
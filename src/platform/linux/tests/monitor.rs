@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+
+use approx::assert_abs_diff_eq;
+
+use crate::platform::netlink::UEvent;
+use crate::units::ratio::percent;
+use crate::{BatteryEvent, Manager, Monitor, Result, UEventSource};
+
+/// Messages captured from the `NETLINK_KOBJECT_UEVENT` socket.
+struct Recorded(VecDeque<&'static [u8]>);
+
+impl UEventSource for Recorded {
+    fn recv(&mut self) -> Result<Option<Vec<u8>>> {
+        Ok(self.0.pop_front().map(|message| message.to_vec()))
+    }
+}
+
+static CHANGE_BAT0: &[u8] =
+    b"change@/devices/LNXSYSTM:00/LNXSYBUS:00/PNP0C0A:00/power_supply/BAT0\0\
+    ACTION=change\0\
+    DEVPATH=/devices/LNXSYSTM:00/LNXSYBUS:00/PNP0C0A:00/power_supply/BAT0\0\
+    SUBSYSTEM=power_supply\0\
+    POWER_SUPPLY_NAME=BAT0\0\
+    POWER_SUPPLY_TYPE=Battery\0\
+    POWER_SUPPLY_STATUS=Discharging\0\
+    POWER_SUPPLY_CAPACITY=69\0\
+    SEQNUM=4242\0";
+
+static CHANGE_AC: &[u8] = b"change@/devices/LNXSYSTM:00/LNXSYBUS:00/ACPI0003:00/power_supply/AC\0\
+    ACTION=change\0\
+    DEVPATH=/devices/LNXSYSTM:00/LNXSYBUS:00/ACPI0003:00/power_supply/AC\0\
+    SUBSYSTEM=power_supply\0\
+    POWER_SUPPLY_NAME=AC\0\
+    POWER_SUPPLY_TYPE=Mains\0\
+    POWER_SUPPLY_ONLINE=0\0\
+    SEQNUM=4243\0";
+
+static ADD_BAT1: &[u8] = b"add@/devices/LNXSYSTM:00/LNXSYBUS:00/PNP0C0A:01/power_supply/BAT1\0\
+    ACTION=add\0\
+    DEVPATH=/devices/LNXSYSTM:00/LNXSYBUS:00/PNP0C0A:01/power_supply/BAT1\0\
+    SUBSYSTEM=power_supply\0\
+    SEQNUM=4244\0";
+
+static REMOVE_BAT1: &[u8] =
+    b"remove@/devices/LNXSYSTM:00/LNXSYBUS:00/PNP0C0A:01/power_supply/BAT1\0\
+    ACTION=remove\0\
+    DEVPATH=/devices/LNXSYSTM:00/LNXSYBUS:00/PNP0C0A:01/power_supply/BAT1\0\
+    SUBSYSTEM=power_supply\0\
+    SEQNUM=4245\0";
+
+static ADD_USB: &[u8] = b"add@/devices/pci0000:00/0000:00:14.0/usb1/1-2\0\
+    ACTION=add\0\
+    DEVPATH=/devices/pci0000:00/0000:00:14.0/usb1/1-2\0\
+    SUBSYSTEM=usb\0\
+    DEVTYPE=usb_device\0\
+    SEQNUM=4246\0";
+
+fn fixture() -> tempfile::TempDir {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 69,
+        "voltage_now" => 12000000
+    );
+    sysfs_test_device!(root.path(), "AC",
+        "type" => "Mains",
+        "online" => 0
+    );
+
+    root
+}
+
+#[test]
+fn test_uevent_message_parse() {
+    let event = UEvent::parse(CHANGE_BAT0).unwrap();
+
+    assert_eq!(event.action, "change");
+    assert_eq!(event.subsystem, "power_supply");
+    assert_eq!(event.name, "BAT0");
+
+    assert_eq!(
+        UEvent::parse(b"ACTION=change\0SUBSYSTEM=power_supply\0"),
+        None
+    );
+    // udev re-broadcasted messages are using the binary header
+    assert_eq!(UEvent::parse(b"libudev\0\xfe\xed\xca\xfe"), None);
+}
+
+#[test]
+fn test_monitor_events() {
+    let root = fixture();
+    let manager = Manager::with_root(root.path()).unwrap();
+    let messages = [CHANGE_BAT0, CHANGE_AC, ADD_USB, ADD_BAT1, REMOVE_BAT1];
+    let monitor = Monitor::with_source(&manager, Recorded(messages.into())).unwrap();
+
+    sysfs_test_device!(root.path(), "BAT1",
+        "type" => "Battery",
+        "status" => "Charging",
+        "capacity" => 12,
+        "voltage_now" => 11000000
+    );
+
+    let events = monitor.collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(events.len(), 3);
+
+    match &events[0] {
+        BatteryEvent::Changed(battery) => {
+            assert_abs_diff_eq!(battery.state_of_charge().get::<percent>(), 69.0);
+        }
+        event => panic!("Unexpected event: {:?}", event),
+    }
+    match &events[1] {
+        BatteryEvent::Added(battery) => {
+            assert_abs_diff_eq!(battery.state_of_charge().get::<percent>(), 12.0);
+        }
+        event => panic!("Unexpected event: {:?}", event),
+    }
+    match &events[2] {
+        BatteryEvent::Removed(name) => assert_eq!(name, "BAT1"),
+        event => panic!("Unexpected event: {:?}", event),
+    }
+}
+
+#[test]
+fn test_monitor_skips_unknown_removal() {
+    let root = fixture();
+    let manager = Manager::with_root(root.path()).unwrap();
+    let monitor = Monitor::with_source(&manager, Recorded([REMOVE_BAT1].into())).unwrap();
+
+    assert_eq!(monitor.count(), 0);
+}
@@ -0,0 +1,93 @@
+use approx::assert_abs_diff_eq;
+
+use super::super::SysFsDevice;
+use crate::platform::traits::BatteryDevice;
+use crate::units::electric_charge::milliampere_hour;
+use crate::units::electric_current::milliampere;
+
+#[test]
+fn test_charge_units() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 3900000,
+        "voltage_min_design" => 3800000,
+        "current_now" => -450000,
+        "charge_now" => 2000000,
+        "charge_full" => 2800000,
+        "charge_full_design" => 3000000,
+        "charge_counter" => 1990000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_abs_diff_eq!(
+        device.current().unwrap().get::<milliampere>(),
+        450.0,
+        epsilon = 0.001
+    );
+    assert_abs_diff_eq!(device.charge().unwrap().get::<milliampere_hour>(), 2000.0);
+    assert_abs_diff_eq!(
+        device.charge_full().unwrap().get::<milliampere_hour>(),
+        2800.0
+    );
+    assert_abs_diff_eq!(
+        device
+            .charge_full_design()
+            .unwrap()
+            .get::<milliampere_hour>(),
+        3000.0
+    );
+    assert_abs_diff_eq!(
+        device.charge_counter().unwrap().get::<milliampere_hour>(),
+        1990.0
+    );
+
+    root.close()
+}
+
+// Legacy drivers are reporting `current_now` in µW if there is no `power_now`
+#[test]
+fn test_energy_units() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "current_now" => 9000000,
+        "energy_now" => 30000000,
+        "energy_full" => 50000000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_eq!(device.current(), None);
+    assert_eq!(device.charge(), None);
+    assert_eq!(device.charge_full(), None);
+    assert_eq!(device.charge_full_design(), None);
+    assert_eq!(device.charge_counter(), None);
+
+    root.close()
+}
+
+#[test]
+fn test_energy_units_with_power() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "current_now" => 750000,
+        "power_now" => 9000000,
+        "energy_now" => 30000000,
+        "energy_full" => 50000000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_abs_diff_eq!(
+        device.current().unwrap().get::<milliampere>(),
+        750.0,
+        epsilon = 0.001
+    );
+
+    root.close()
+}
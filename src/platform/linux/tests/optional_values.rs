@@ -0,0 +1,75 @@
+use approx::assert_abs_diff_eq;
+
+use crate::units::electric_potential::volt;
+use crate::units::energy::watt_hour;
+use crate::units::ratio::percent;
+use crate::Manager;
+
+#[test]
+fn test_missing_values() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 70
+    );
+
+    let manager = Manager::builder().sysfs_root(root.path()).build().unwrap();
+    let battery = manager.batteries().unwrap().next().unwrap().unwrap();
+
+    assert_abs_diff_eq!(battery.state_of_charge().get::<percent>(), 70.0);
+    assert_eq!(battery.try_voltage(), None);
+    assert_eq!(battery.try_energy(), None);
+    assert_eq!(battery.try_energy_full(), None);
+    assert_eq!(battery.try_energy_full_design(), None);
+    assert_eq!(battery.try_energy_rate(), None);
+    assert_eq!(battery.try_state_of_health(), None);
+}
+
+#[test]
+fn test_missing_design_capacity() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "power_now" => 10000000,
+        "energy_now" => 30000000,
+        "energy_full" => 50000000
+    );
+
+    let manager = Manager::builder().sysfs_root(root.path()).build().unwrap();
+    let battery = manager.batteries().unwrap().next().unwrap().unwrap();
+
+    assert_abs_diff_eq!(battery.try_voltage().unwrap().get::<volt>(), 12.0);
+    assert_abs_diff_eq!(battery.try_energy().unwrap().get::<watt_hour>(), 30.0);
+    assert_abs_diff_eq!(battery.try_energy_full().unwrap().get::<watt_hour>(), 50.0);
+    assert!(battery.try_energy_rate().is_some());
+    assert_eq!(battery.try_energy_full_design(), None);
+    assert_eq!(battery.try_state_of_health(), None);
+}
+
+#[test]
+fn test_missing_full_capacity() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "power_now" => 10000000,
+        "energy_now" => 30000000,
+        "energy_full_design" => 55000000
+    );
+
+    let manager = Manager::builder().sysfs_root(root.path()).build().unwrap();
+    let battery = manager.batteries().unwrap().next().unwrap().unwrap();
+
+    // Full capacity falls back to the design one, but it is not reported as measured
+    assert_abs_diff_eq!(battery.energy_full().get::<watt_hour>(), 55.0);
+    assert_abs_diff_eq!(
+        battery.try_energy_full_design().unwrap().get::<watt_hour>(),
+        55.0
+    );
+    assert_eq!(battery.try_energy_full(), None);
+    assert_eq!(battery.try_state_of_health(), None);
+}
@@ -0,0 +1,86 @@
+use approx::assert_abs_diff_eq;
+
+use crate::{DeviceKind, Manager, Scope};
+
+fn fixture() -> tempfile::TempDir {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 70,
+        "energy_now" => 35000000,
+        "energy_full" => 50000000,
+        "voltage_now" => 12000000
+    );
+    sysfs_test_device!(root.path(), "hidpp_battery_0",
+        "type" => "Battery",
+        "scope" => "Device",
+        "status" => "Discharging",
+        "capacity" => 55,
+        "model_name" => "MX Master 3",
+        "online" => 1
+    );
+    let headset = sysfs_test_device!(root.path(), "hid-00:1b:66:aa:bb:cc-battery",
+        "type" => "Battery",
+        "scope" => "Device",
+        "status" => "Charging",
+        "capacity" => 20,
+        "model_name" => "WH-1000XM4 Headphones"
+    );
+    // Logitech receivers are not giving any hint besides the registered input handlers
+    std::fs::create_dir_all(root.path().join("hid-mouse/input/input23/mouse2")).unwrap();
+    std::fs::create_dir(headset.join("input")).unwrap();
+    std::os::unix::fs::symlink(
+        root.path().join("hid-mouse"),
+        root.path().join("hidpp_battery_0/device"),
+    )
+    .unwrap();
+
+    root
+}
+
+#[test]
+fn test_peripherals_are_skipped_by_default() {
+    let root = fixture();
+    let manager = Manager::with_root(root.path()).unwrap();
+    let batteries = manager
+        .batteries()
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(batteries.len(), 1);
+    assert_eq!(batteries[0].scope(), Scope::System);
+    assert_eq!(batteries[0].device_kind(), None);
+}
+
+#[test]
+fn test_peripherals() {
+    let root = fixture();
+    let manager = Manager::builder()
+        .sysfs_root(root.path())
+        .include_peripherals(true)
+        .build()
+        .unwrap();
+    let mut batteries = manager
+        .batteries()
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+    batteries.sort_by(|a, b| a.model().cmp(&b.model()));
+
+    assert_eq!(batteries.len(), 3);
+    assert_eq!(batteries[0].device_kind(), None);
+
+    let mouse = &batteries[1];
+    assert_eq!(mouse.scope(), Scope::Device);
+    assert_eq!(mouse.device_kind(), Some(DeviceKind::Mouse));
+    assert_eq!(mouse.model(), Some("MX Master 3"));
+    assert_abs_diff_eq!(mouse.state_of_charge().value, 0.55);
+    assert_abs_diff_eq!(mouse.voltage().value, 0.0);
+
+    let headset = &batteries[2];
+    assert_eq!(headset.scope(), Scope::Device);
+    assert_eq!(headset.device_kind(), Some(DeviceKind::Headset));
+    assert_abs_diff_eq!(headset.state_of_charge().value, 0.2);
+}
@@ -0,0 +1,146 @@
+use std::fs;
+
+use approx::assert_abs_diff_eq;
+
+use super::super::SysFsDevice;
+use crate::platform::traits::BatteryDevice;
+use crate::units::power::watt;
+use crate::PowerFlow;
+
+#[test]
+fn test_negative_power_now() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Unknown",
+        "voltage_now" => 12000000,
+        "power_now" => -9500000,
+        "energy_now" => 30000000,
+        "energy_full" => 50000000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_abs_diff_eq!(device.energy_rate().get::<watt>(), 9.5, epsilon = 0.001);
+    assert_eq!(
+        device.power_flow(),
+        PowerFlow::Discharging(device.energy_rate())
+    );
+    assert_abs_diff_eq!(
+        device.power_flow().power().get::<watt>(),
+        -9.5,
+        epsilon = 0.001
+    );
+
+    root.close()
+}
+
+#[test]
+fn test_negative_current_now() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 4000000,
+        "voltage_max_design" => 4000000,
+        "current_now" => -500000,
+        "charge_now" => 2000000,
+        "charge_full" => 3000000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_abs_diff_eq!(device.energy_rate().get::<watt>(), 2.0, epsilon = 0.001);
+    assert_abs_diff_eq!(
+        device.power_flow().power().get::<watt>(),
+        -2.0,
+        epsilon = 0.001
+    );
+
+    root.close()
+}
+
+#[test]
+fn test_unsigned_power_now_with_unknown_state() -> std::io::Result<()> {
+    let root = tempfile::tempdir()?;
+    let path = sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Unknown",
+        "voltage_now" => 12000000,
+        "power_now" => 9500000,
+        "energy_now" => 30000000,
+        "energy_full" => 50000000
+    );
+
+    // Most of the drivers are reporting unsigned values,
+    // so the direction can't be inferred without the adapters
+    let device = SysFsDevice::try_from(path.clone()).unwrap();
+    assert_abs_diff_eq!(device.energy_rate().get::<watt>(), 9.5, epsilon = 0.001);
+    assert_eq!(device.power_flow(), PowerFlow::Idle);
+
+    // Battery is charging from the online adapter
+    let ac = sysfs_test_device!(root.path(), "AC",
+        "type" => "Mains",
+        "online" => 1
+    );
+    sysfs_test_device!(root.path(), "ucsi-source-psy-USBC000:001",
+        "type" => "USB",
+        "online" => 0
+    );
+    let device = SysFsDevice::try_from(path.clone()).unwrap();
+    assert_eq!(
+        device.power_flow(),
+        PowerFlow::Charging(device.energy_rate())
+    );
+
+    // And it is powering the system if all the adapters are offline
+    fs::write(ac.join("online"), "0")?;
+    let device = SysFsDevice::try_from(path).unwrap();
+    assert_eq!(
+        device.power_flow(),
+        PowerFlow::Discharging(device.energy_rate())
+    );
+
+    root.close()
+}
+
+#[test]
+fn test_peripheral_with_unknown_state() -> std::io::Result<()> {
+    let root = tempfile::tempdir()?;
+    sysfs_test_device!(root.path(), "AC",
+        "type" => "Mains",
+        "online" => 1
+    );
+    let path = sysfs_test_device!(root.path(), "hidpp_battery_0",
+        "type" => "Battery",
+        "scope" => "Device",
+        "status" => "Unknown",
+        "voltage_now" => 3900000,
+        "power_now" => 500000,
+        "energy_now" => 1000000,
+        "energy_full" => 2000000
+    );
+
+    // Peripherals are not charged by the system adapters
+    let device = SysFsDevice::try_from(path).unwrap();
+    assert_eq!(device.power_flow(), PowerFlow::Idle);
+
+    root.close()
+}
+
+#[test]
+fn test_idle() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Full",
+        "voltage_now" => 12000000,
+        "power_now" => 0,
+        "energy_now" => 50000000,
+        "energy_full" => 50000000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_eq!(device.power_flow(), PowerFlow::Idle);
+    assert_abs_diff_eq!(device.power_flow().power().get::<watt>(), 0.0);
+
+    root.close()
+}
@@ -0,0 +1,109 @@
+use approx::assert_abs_diff_eq;
+
+use super::super::SysFsDevice;
+use crate::platform::traits::BatteryDevice;
+use crate::units::ratio::percent;
+use crate::Provenance;
+
+#[test]
+fn test_energy_units() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "power_now" => 9000000,
+        "energy_now" => 30000000,
+        "energy_full" => 50000000,
+        "energy_full_design" => 55000000,
+        "capacity" => 60,
+        "capacity_error_margin" => 2
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+    let provenance = device.provenance();
+
+    assert_eq!(provenance.energy(), Provenance::Measured);
+    assert_eq!(provenance.energy_full(), Provenance::Measured);
+    assert_eq!(provenance.energy_full_design(), Provenance::Measured);
+    assert_eq!(provenance.energy_rate(), Provenance::Measured);
+    assert_eq!(provenance.state_of_charge(), Provenance::Measured);
+    assert_eq!(provenance.state_of_health(), Provenance::Measured);
+    assert_abs_diff_eq!(
+        device.capacity_error_margin().unwrap().get::<percent>(),
+        2.0
+    );
+
+    root.close()
+}
+
+#[test]
+fn test_charge_units() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "current_now" => 500000,
+        "charge_now" => 2000000,
+        "charge_full" => 4000000,
+        "charge_full_design" => 4400000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+    let provenance = device.provenance();
+
+    assert_eq!(provenance.energy(), Provenance::DerivedFromCharge);
+    assert_eq!(provenance.energy_full(), Provenance::DerivedFromCharge);
+    assert_eq!(
+        provenance.energy_full_design(),
+        Provenance::DerivedFromCharge
+    );
+    assert_eq!(provenance.energy_rate(), Provenance::DerivedFromCharge);
+    assert_eq!(provenance.state_of_charge(), Provenance::DerivedFromCharge);
+    assert_eq!(device.capacity_error_margin(), None);
+
+    root.close()
+}
+
+// Same as the #40 issue, but with capacity only
+#[test]
+fn test_capacity_only() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "capacity" => 40
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+    let provenance = device.provenance();
+
+    assert_eq!(provenance.state_of_charge(), Provenance::Measured);
+    assert_eq!(provenance.energy_full_design(), Provenance::Defaulted);
+    assert_eq!(provenance.energy_full(), Provenance::Defaulted);
+    assert_eq!(provenance.energy(), Provenance::Defaulted);
+    assert_eq!(provenance.energy_rate(), Provenance::Defaulted);
+    assert_eq!(provenance.state_of_health(), Provenance::Defaulted);
+
+    root.close()
+}
+
+#[test]
+fn test_design_energy_only() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "power_now" => 9000000,
+        "energy_now" => 30000000,
+        "energy_full_design" => 55000000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+    let provenance = device.provenance();
+
+    assert_eq!(provenance.energy_full_design(), Provenance::Measured);
+    assert_eq!(provenance.energy_full(), Provenance::Defaulted);
+    assert_eq!(provenance.state_of_health(), Provenance::Defaulted);
+
+    root.close()
+}
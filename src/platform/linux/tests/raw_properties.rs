@@ -0,0 +1,95 @@
+use super::super::SysFsDevice;
+use crate::platform::traits::BatteryDevice;
+use std::io::ErrorKind;
+
+#[test]
+fn test_raw_properties() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "uevent" => "DEVTYPE=power_supply\nPOWER_SUPPLY_NAME=BAT0\nPOWER_SUPPLY_STATUS=Charging\nPOWER_SUPPLY_CAPACITY=40",
+        "type" => "Battery",
+        "voltage_now" => 12000000,
+        // Value from the `uevent` takes precedence
+        "capacity" => 10,
+        "charge_control_end_threshold" => 80
+    );
+    std::fs::create_dir(root.path().join("power")).unwrap();
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+    let properties = device.raw_properties();
+
+    assert_eq!(
+        properties.keys().collect::<Vec<_>>(),
+        [
+            "capacity",
+            "charge_control_end_threshold",
+            "name",
+            "status",
+            "type",
+            "voltage_now"
+        ]
+    );
+    assert_eq!(properties["capacity"], "40");
+    assert_eq!(properties["charge_control_end_threshold"], "80");
+
+    root.close()
+}
+
+#[test]
+fn test_read_property() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "capacity" => 40,
+        "charge_control_end_threshold" => 80
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+    std::fs::write(root.path().join("charge_control_end_threshold"), "60\n").unwrap();
+
+    assert_eq!(
+        device
+            .read_property("charge_control_end_threshold")
+            .unwrap(),
+        Some("60".to_string())
+    );
+    assert_eq!(device.read_property("charge_type").unwrap(), None);
+    assert_eq!(
+        device.read_property("../BAT1/capacity").unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+
+    root.close()
+}
+
+#[test]
+fn test_raw_properties_are_read_on_demand() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "capacity" => 40
+    );
+
+    let mut device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+    // Not read yet, so the file written after the device was created is there
+    std::fs::write(root.path().join("charge_control_end_threshold"), "80\n").unwrap();
+    assert_eq!(
+        device.raw_properties()["charge_control_end_threshold"],
+        "80"
+    );
+
+    // Kept until the refresh
+    std::fs::write(root.path().join("charge_control_end_threshold"), "60\n").unwrap();
+    assert_eq!(
+        device.raw_properties()["charge_control_end_threshold"],
+        "80"
+    );
+    device.refresh().unwrap();
+    assert_eq!(
+        device.raw_properties()["charge_control_end_threshold"],
+        "60"
+    );
+
+    root.close()
+}
@@ -0,0 +1,125 @@
+use std::fs;
+use std::io;
+
+use approx::assert_abs_diff_eq;
+
+use crate::units::ratio::percent;
+use crate::{BatteryId, Manager};
+
+#[test]
+fn test_refresh_all() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 70
+    );
+    sysfs_test_device!(root.path(), "BAT1",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 40
+    );
+
+    let manager = Manager::builder().sysfs_root(root.path()).build().unwrap();
+    let mut batteries = Vec::new();
+
+    let summary = manager.refresh_all(&mut batteries).unwrap();
+    assert!(summary.is_changed());
+    assert_eq!(summary.added().len(), 2);
+    assert!(summary.refreshed().is_empty());
+    assert!(summary.removed().is_empty());
+    assert_eq!(batteries.len(), 2);
+
+    fs::write(root.path().join("BAT0").join("capacity"), "65").unwrap();
+    fs::remove_dir_all(root.path().join("BAT1")).unwrap();
+    sysfs_test_device!(root.path(), "BAT2",
+        "type" => "Battery",
+        "status" => "Charging",
+        "capacity" => 10
+    );
+
+    let summary = manager.refresh_all(&mut batteries).unwrap();
+    assert!(summary.is_changed());
+    let names = |ids: &[BatteryId]| {
+        ids.iter()
+            .map(|id| id.name().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(summary.refreshed()), ["BAT0"]);
+    assert_eq!(names(summary.removed()), ["BAT1"]);
+    assert_eq!(names(summary.added()), ["BAT2"]);
+
+    assert_eq!(batteries.len(), 2);
+    assert_eq!(batteries[0].id().name(), "BAT0");
+    assert_abs_diff_eq!(
+        batteries[0].state_of_charge().get::<percent>(),
+        65.0,
+        epsilon = 0.001
+    );
+    assert_eq!(batteries[1].id().name(), "BAT2");
+
+    let summary = manager.refresh_all(&mut batteries).unwrap();
+    assert!(!summary.is_changed());
+    assert_eq!(summary.refreshed().len(), 2);
+}
+
+#[test]
+fn test_battery_removed_during_refresh() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 70
+    );
+    sysfs_test_device!(root.path(), "BAT1",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 40
+    );
+
+    let manager = Manager::builder().sysfs_root(root.path()).build().unwrap();
+    let mut batteries = manager
+        .batteries()
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+    batteries.sort_by_key(|battery| battery.id());
+
+    // Battery is unplugged after it was enumerated, but before it is refreshed,
+    // and there is an unreadable power supply, which should not fail the refresh
+    fs::remove_dir_all(root.path().join("BAT0")).unwrap();
+    fs::create_dir_all(root.path().join("BAT2").join("type")).unwrap();
+
+    let summary = manager.refresh_all(&mut batteries).unwrap();
+    assert_eq!(summary.removed().len(), 1);
+    assert_eq!(summary.removed()[0].name(), "BAT0");
+    assert_eq!(summary.refreshed().len(), 1);
+    assert!(summary.added().is_empty());
+
+    assert_eq!(batteries.len(), 1);
+    assert_eq!(batteries[0].id().name(), "BAT1");
+}
+
+#[test]
+fn test_battery_without_energy_values() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "capacity" => 70
+    );
+
+    let manager = Manager::builder().sysfs_root(root.path()).build().unwrap();
+    let mut batteries = Vec::new();
+    manager.refresh_all(&mut batteries).unwrap();
+    assert_eq!(batteries.len(), 1);
+
+    // Battery is still available, but it is not possible to calculate its energy anymore
+    fs::remove_file(root.path().join("BAT0").join("capacity")).unwrap();
+
+    let e = manager.refresh_all(&mut batteries).unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::NotFound);
+    assert!(!e.is_device_removed());
+    assert_eq!(batteries.len(), 1);
+    assert_eq!(batteries[0].id().name(), "BAT0");
+}
@@ -0,0 +1,72 @@
+use approx::assert_abs_diff_eq;
+
+use crate::units::power::watt;
+use crate::units::time::day;
+use crate::units::Power;
+use crate::{Manager, SanitizePolicy};
+
+fn fixture() -> tempfile::TempDir {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Charging",
+        "voltage_now" => 20000000,
+        "power_now" => 150000000,
+        "energy_now" => 30000000,
+        "energy_full" => 99000000
+    );
+    sysfs_test_device!(root.path(), "BAT1",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 48000000,
+        "power_now" => 2000000,
+        "energy_now" => 960000000,
+        "energy_full" => 1000000000
+    );
+
+    root
+}
+
+fn batteries(manager: &Manager) -> Vec<crate::Battery> {
+    let mut batteries = manager
+        .batteries()
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+    batteries.sort_by_key(|battery| battery.energy_full().value as u64);
+
+    batteries
+}
+
+#[test]
+fn test_default_policy() {
+    let root = fixture();
+    let manager = Manager::with_root(root.path()).unwrap();
+    let batteries = batteries(&manager);
+
+    assert_abs_diff_eq!(batteries[0].energy_rate().get::<watt>(), 0.0);
+    assert_eq!(batteries[0].time_to_full(), None);
+    assert_eq!(batteries[1].time_to_empty(), None);
+}
+
+#[test]
+fn test_custom_policy() {
+    let root = fixture();
+    let policy = SanitizePolicy::default()
+        .max_energy_rate(Some(Power::new::<watt>(240.0)))
+        .max_time_to_empty(None);
+    let manager = Manager::builder()
+        .sysfs_root(root.path())
+        .sanitize_policy(policy)
+        .build()
+        .unwrap();
+    let batteries = batteries(&manager);
+
+    assert_abs_diff_eq!(batteries[0].energy_rate().get::<watt>(), 150.0);
+    assert!(batteries[0].time_to_full().is_some());
+    assert_abs_diff_eq!(
+        batteries[1].time_to_empty().unwrap().get::<day>(),
+        20.0,
+        epsilon = 0.001
+    );
+}
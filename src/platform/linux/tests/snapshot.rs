@@ -0,0 +1,71 @@
+use std::time::SystemTime;
+
+use approx::assert_abs_diff_eq;
+
+use crate::{BatterySnapshot, Manager, Provenance, State, Technology};
+
+#[test]
+fn test_snapshot() {
+    let root = tempfile::tempdir().unwrap();
+    sysfs_test_device!(root.path(), "BAT0",
+        "type" => "Battery",
+        "status" => "Discharging",
+        "technology" => "Li-ion",
+        "serial_number" => "1234",
+        "voltage_now" => 12000000,
+        "power_now" => 10000000,
+        "energy_now" => 30000000,
+        "energy_full" => 50000000,
+        "capacity" => 60
+    );
+
+    let manager = Manager::builder().sysfs_root(root.path()).build().unwrap();
+    let battery = manager.batteries().unwrap().next().unwrap().unwrap();
+    let before = SystemTime::now();
+    let snapshot = battery.snapshot();
+
+    assert!(snapshot.timestamp >= before);
+    assert_eq!(snapshot.id, battery.id());
+    assert_eq!(snapshot.serial_number.as_deref(), Some("1234"));
+    assert_eq!(snapshot.technology, Technology::LithiumIon);
+    assert_eq!(snapshot.state, State::Discharging);
+    assert_abs_diff_eq!(snapshot.percentage, 60.0, epsilon = 0.001);
+    assert_abs_diff_eq!(snapshot.energy_wh.unwrap(), 30.0, epsilon = 0.001);
+    assert_abs_diff_eq!(snapshot.energy_full_wh.unwrap(), 50.0, epsilon = 0.001);
+    assert_abs_diff_eq!(snapshot.energy_rate_w.unwrap(), 10.0, epsilon = 0.001);
+    assert_abs_diff_eq!(snapshot.power_flow_w, -10.0, epsilon = 0.001);
+    assert_abs_diff_eq!(snapshot.voltage_v.unwrap(), 12.0, epsilon = 0.001);
+    assert_abs_diff_eq!(
+        snapshot.time_to_empty_secs.unwrap(),
+        3.0 * 3600.0,
+        epsilon = 0.1
+    );
+    assert_eq!(snapshot.time_to_full_secs, None);
+    // Design capacity is missing
+    assert_eq!(snapshot.energy_full_design_wh, None);
+    assert_eq!(snapshot.state_of_health_percentage, None);
+    assert_eq!(
+        snapshot.provenance.energy_full_design(),
+        Provenance::Defaulted
+    );
+    assert_eq!(snapshot.battery_type.as_deref(), Some("Li-ion"));
+    // Raw properties are not read for the default snapshot
+    assert_eq!(snapshot.raw_properties, None);
+
+    let snapshot = battery.snapshot_with_raw_properties();
+    assert_eq!(
+        snapshot
+            .raw_properties
+            .as_ref()
+            .and_then(|properties| properties.get("serial_number"))
+            .map(String::as_str),
+        Some("1234")
+    );
+}
+
+#[test]
+fn test_snapshot_is_plain_data() {
+    fn assert_plain<T: Clone + Send + Sync + 'static>() {}
+
+    assert_plain::<BatterySnapshot>();
+}
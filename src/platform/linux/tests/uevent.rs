@@ -0,0 +1,51 @@
+use approx::assert_abs_diff_eq;
+
+use super::super::SysFsDevice;
+use crate::platform::traits::BatteryDevice;
+use crate::{State, Technology};
+
+static UEVENT: &str = "DEVTYPE=power_supply
+POWER_SUPPLY_NAME=BAT0
+POWER_SUPPLY_TYPE=Battery
+POWER_SUPPLY_STATUS=Discharging
+POWER_SUPPLY_PRESENT=1
+POWER_SUPPLY_TECHNOLOGY=Li-poly
+POWER_SUPPLY_CYCLE_COUNT=113
+POWER_SUPPLY_VOLTAGE_MIN_DESIGN=15440000
+POWER_SUPPLY_VOLTAGE_NOW=16584000
+POWER_SUPPLY_POWER_NOW=7280000
+POWER_SUPPLY_ENERGY_FULL_DESIGN=57000000
+POWER_SUPPLY_ENERGY_FULL=51360000
+POWER_SUPPLY_ENERGY_NOW=40270000
+POWER_SUPPLY_CAPACITY=78
+POWER_SUPPLY_CAPACITY_LEVEL=Normal
+POWER_SUPPLY_MODEL_NAME=5B10W13930
+POWER_SUPPLY_MANUFACTURER=SMP";
+
+#[test]
+fn test_uevent() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "uevent" => UEVENT,
+        // Attributes missing in the `uevent` are read from the separate files
+        "serial_number" => "1234",
+        // Separate files are ignored if attribute exists in the `uevent`
+        "capacity" => 10
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_eq!(device.state(), State::Discharging);
+    assert_eq!(device.technology(), Technology::LithiumPolymer);
+    assert_eq!(device.cycle_count(), Some(113));
+    assert_eq!(device.vendor(), Some("SMP"));
+    assert_eq!(device.model(), Some("5B10W13930"));
+    assert_eq!(device.serial_number(), Some("1234"));
+    assert_abs_diff_eq!(device.state_of_charge().value, 0.78);
+    assert_abs_diff_eq!(device.energy().value, 144972.0);
+    assert_abs_diff_eq!(device.energy_full().value, 184896.0);
+    assert_abs_diff_eq!(device.energy_full_design().value, 205200.0);
+    assert_abs_diff_eq!(device.energy_rate().value, 7.28);
+    assert_abs_diff_eq!(device.voltage().value, 16.584);
+
+    root.close()
+}
@@ -0,0 +1,77 @@
+use approx::assert_abs_diff_eq;
+
+use super::super::SysFsDevice;
+use crate::platform::traits::BatteryDevice;
+use crate::units::electric_potential::volt;
+use crate::units::energy::watt_hour;
+use crate::DesignVoltageSource;
+
+#[test]
+fn test_voltage_values() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 11900000,
+        "voltage_avg" => 12000000,
+        "voltage_ocv" => 12100000,
+        "voltage_min_design" => 10800000,
+        "voltage_max_design" => 12600000,
+        "charge_now" => 2000000,
+        "charge_full" => 4000000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_abs_diff_eq!(device.voltage_avg().unwrap().get::<volt>(), 12.0);
+    assert_abs_diff_eq!(device.voltage_ocv().unwrap().get::<volt>(), 12.1);
+    assert_abs_diff_eq!(device.voltage_min_design().unwrap().get::<volt>(), 10.8);
+    assert_abs_diff_eq!(device.voltage_max_design().unwrap().get::<volt>(), 12.6);
+    assert_eq!(
+        device.design_voltage_source(),
+        Some(DesignVoltageSource::MaxDesign)
+    );
+    assert_abs_diff_eq!(device.energy().get::<watt_hour>(), 25.2, epsilon = 0.001);
+
+    root.close()
+}
+
+#[test]
+fn test_fallback_to_voltage_now() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "charge_now" => 2000000,
+        "charge_full" => 4000000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_eq!(device.voltage_min_design(), None);
+    assert_eq!(device.voltage_max_design(), None);
+    assert_eq!(
+        device.design_voltage_source(),
+        Some(DesignVoltageSource::Now)
+    );
+
+    root.close()
+}
+
+#[test]
+fn test_energy_units() -> std::io::Result<()> {
+    let root = sysfs_test_suite!(
+        "type" => "Battery",
+        "status" => "Discharging",
+        "voltage_now" => 12000000,
+        "voltage_min_design" => 10800000,
+        "energy_now" => 30000000,
+        "energy_full" => 50000000
+    );
+
+    let device = SysFsDevice::try_from(root.path().to_owned()).unwrap();
+
+    assert_abs_diff_eq!(device.voltage_min_design().unwrap().get::<volt>(), 10.8);
+    assert_eq!(device.design_voltage_source(), None);
+
+    root.close()
+}
@@ -2,9 +2,19 @@ cfg_if! {
     if #[cfg(target_os = "linux")] {
         mod linux;
 
-        pub type Manager = linux::SysFsManager;
-        pub type Iterator = linux::SysFsIterator;
-        pub type Device = linux::SysFsDevice;
+        cfg_if! {
+            if #[cfg(feature = "upower")] {
+                pub type Manager = linux::BackendManager;
+                pub type Iterator = linux::BackendIterator;
+                pub type Device = linux::BackendDevice;
+            } else {
+                pub type Manager = linux::SysFsManager;
+                pub type Iterator = linux::SysFsIterator;
+                pub type Device = linux::SysFsDevice;
+            }
+        }
+
+        pub use self::linux::netlink;
     } else if #[cfg(any(target_os = "macos", target_os = "ios"))] {
         mod darwin;
 
@@ -35,4 +45,9 @@ cfg_if! {
     }
 }
 
+// Declared outside of the `cfg_if!` macro, since rustfmt is not visiting
+// the modules declared in the nested macro invocations
+#[cfg(all(target_os = "linux", feature = "upower"))]
+mod upower;
+
 pub mod traits;
@@ -67,7 +67,7 @@ impl<'a> EnvSysDevice<'a> {
         }
 
         if Self::val_cur_value(&sensor_slice[ACPIBAT_PRESENT])? != 1 {
-            return Err(Error::not_found("Battery absent"));
+            return Err(Error::device_removed("Battery absent"));
         }
 
         if !Self::validate(&sensor_slice[ACPIBAT_CAPACITY])?.get_rbool("want-percentage")? {
@@ -256,6 +256,10 @@ impl BatteryDevice for SysMonDevice {
         None
     }
 
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
     fn technology(&self) -> Technology {
         Technology::Unknown
     }
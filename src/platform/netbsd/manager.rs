@@ -23,7 +23,7 @@ impl BatteryManager for SysMonManager {
 
         match envsys.get(device.name.as_str()) {
             Some(sensor) => device.refresh(EnvSysDevice::new(device.name.to_owned(), sensor)?),
-            None => Err(Error::not_found("Could not refresh battery")),
+            None => Err(Error::device_removed("Could not refresh battery")),
         }
     }
 }
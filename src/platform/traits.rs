@@ -1,22 +1,95 @@
 //! Platform-specific types are required to implement the following traits.
 
+use std::collections::{BTreeMap, HashSet};
 use std::fmt::Debug;
 use std::sync::Arc;
 
 use num_traits::identities::Zero;
-use uom::si::time::{day, hour};
 
 use crate::units::{
-    Bound, ElectricPotential, Energy, Power, Ratio, ThermodynamicTemperature, Time,
+    Bound, ElectricCharge, ElectricCurrent, ElectricPotential, Energy, Power, Ratio,
+    ThermodynamicTemperature, Time,
+};
+use crate::{
+    Adapter, BatteryId, CapacityLevel, ChargeBehaviour, ChargeThresholds, ChargeType,
+    DataProvenance, DesignVoltageSource, DeviceKind, Error, Health, Identity, ManagerBuilder,
+    ManufactureDate, PowerFlow, Provenance, Result, SanitizePolicy, Scope, State, Technology,
 };
-use crate::{Result, State, Technology};
 
 pub trait BatteryManager: Debug + Sized {
     type Iterator: BatteryIterator;
 
     fn new() -> Result<Self>;
 
+    /// Platforms without any configurable options are falling back
+    /// to the default `Self::new()` constructor.
+    fn with_builder(_builder: &ManagerBuilder) -> Result<Self> {
+        Self::new()
+    }
+
     fn refresh(&self, battery: &mut <Self::Iterator as BatteryIterator>::Device) -> Result<()>;
+
+    /// Builds the battery device with the `name` directly, `None` if there is no such battery.
+    ///
+    /// Platforms which are unable to do that are returning the `Unsupported` error,
+    /// so the caller falls back to the enumeration.
+    fn battery(&self, _name: &str) -> Result<Option<<Self::Iterator as BatteryIterator>::Device>> {
+        Err(Error::unsupported(
+            "Battery lookup by name is not supported on this platform",
+        ))
+    }
+
+    /// Names of the available batteries, same as the `BatteryDevice::name` returns,
+    /// which allows to find the new ones without building all the devices.
+    ///
+    /// Platforms which are unable to list them are returning the `Unsupported` error.
+    fn battery_names(&self) -> Result<HashSet<String>> {
+        Err(Error::unsupported(
+            "Battery names listing is not supported on this platform",
+        ))
+    }
+
+    /// Power supplies, which are not batteries.
+    ///
+    /// Platforms which are not supporting them are yielding nothing.
+    fn adapters(&self) -> Result<Box<dyn Iterator<Item = Result<Adapter>> + Send>> {
+        Ok(Box::new(std::iter::empty()))
+    }
+
+    /// Values are already validated by the caller,
+    /// `None` means that threshold should stay unchanged.
+    fn set_charge_thresholds(
+        &self,
+        _battery: &<Self::Iterator as BatteryIterator>::Device,
+        _start: Option<Ratio>,
+        _end: Option<Ratio>,
+    ) -> Result<()> {
+        Err(Error::unsupported(
+            "Charge thresholds are not supported on this platform",
+        ))
+    }
+
+    /// Value is already validated against the available behaviours by the caller.
+    fn set_charge_behaviour(
+        &self,
+        _battery: &<Self::Iterator as BatteryIterator>::Device,
+        _behaviour: ChargeBehaviour,
+    ) -> Result<()> {
+        Err(Error::unsupported(
+            "Charge behaviour is not supported on this platform",
+        ))
+    }
+
+    /// Value is already validated against the available charge types by the caller.
+    fn set_charge_type(
+        &self,
+        _battery: &<Self::Iterator as BatteryIterator>::Device,
+        _charge_type: ChargeType,
+    ) -> Result<()> {
+        Err(Error::unsupported(
+            "Charge type is not supported on this platform",
+        ))
+    }
 }
 
 pub trait BatteryIterator:
@@ -72,55 +145,236 @@ pub trait BatteryDevice: Sized + Debug {
 
     fn cycle_count(&self) -> Option<u32>;
 
+    fn manufacture_date(&self) -> Option<ManufactureDate> {
+        None
+    }
+
+    /// Platform device name, which is not changing between the enumerations.
+    fn name(&self) -> String;
+
+    fn id(&self) -> BatteryId {
+        BatteryId::new(self.name(), self.serial_number())
+    }
+
+    /// Platforms with additional identification attributes are extending the default value.
+    fn identity(&self) -> Identity {
+        Identity {
+            vendor: self.vendor().map(ToString::to_string),
+            model: self.model().map(ToString::to_string),
+            serial_number: self.serial_number().map(ToString::to_string),
+            manufacture_date: self.manufacture_date(),
+            ..Identity::default()
+        }
+    }
+
+    fn capacity_level(&self) -> Option<CapacityLevel> {
+        None
+    }
+
+    fn power_flow(&self) -> PowerFlow {
+        PowerFlow::new(self.state(), self.energy_rate())
+    }
+
+    // Native charge values are available only for devices reporting them,
+    // they are not calculated from the energy values
+
+    fn current(&self) -> Option<ElectricCurrent> {
+        None
+    }
+
+    fn charge(&self) -> Option<ElectricCharge> {
+        None
+    }
+
+    fn charge_full(&self) -> Option<ElectricCharge> {
+        None
+    }
+
+    fn charge_full_design(&self) -> Option<ElectricCharge> {
+        None
+    }
+
+    fn charge_counter(&self) -> Option<ElectricCharge> {
+        None
+    }
+
+    fn voltage_min_design(&self) -> Option<ElectricPotential> {
+        None
+    }
+
+    fn voltage_max_design(&self) -> Option<ElectricPotential> {
+        None
+    }
+
+    fn voltage_ocv(&self) -> Option<ElectricPotential> {
+        None
+    }
+
+    fn voltage_avg(&self) -> Option<ElectricPotential> {
+        None
+    }
+
+    fn design_voltage_source(&self) -> Option<DesignVoltageSource> {
+        None
+    }
+
+    fn health(&self) -> Option<Health> {
+        None
+    }
+
+    fn scope(&self) -> Scope {
+        Scope::System
+    }
+
+    fn sanitize_policy(&self) -> SanitizePolicy {
+        SanitizePolicy::default()
+    }
+
+    /// Platforms which are not calculating any values are reporting all of them as measured,
+    /// except for the zeroes reported for the missing ones.
+    fn provenance(&self) -> DataProvenance {
+        missing_values_provenance(self)
+    }
+
+    fn capacity_error_margin(&self) -> Option<Ratio> {
+        None
+    }
+
+    fn raw_properties(&self) -> &BTreeMap<String, String> {
+        static EMPTY: BTreeMap<String, String> = BTreeMap::new();
+
+        &EMPTY
+    }
+
+    /// Platforms without the named properties are reporting all of them as missing.
+    fn read_property(&self, _name: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    fn device_kind(&self) -> Option<DeviceKind> {
+        None
+    }
+
+    fn charge_thresholds(&self) -> Option<ChargeThresholds> {
+        None
+    }
+
+    fn charge_behaviour(&self) -> Option<ChargeBehaviour> {
+        None
+    }
+
+    fn available_charge_behaviours(&self) -> &[ChargeBehaviour] {
+        &[]
+    }
+
+    fn charge_type(&self) -> Option<ChargeType> {
+        None
+    }
+
+    fn available_charge_types(&self) -> &[ChargeType] {
+        &[]
+    }
+
     // Default implementation for `time_to_full` and `time_to_empty`
     // uses calculation based on the current energy flow,
     // but if device provides by itself provides these **instant** values (do not use average values),
     // it would be easier and cheaper to return them instead of making some calculations
 
     fn time_to_full(&self) -> Option<Time> {
-        let energy_rate = self.energy_rate();
-        match self.state() {
-            // In some cases energy_rate can be 0 while Charging, for example just after
-            // plugging in the charger. Assume that the battery doesn't have time_to_full in such
-            // cases, to avoid division by zero. See https://github.com/svartalf/rust-battery/pull/5
-            State::Charging if !energy_rate.is_zero() => {
-                // Some drivers might report that `energy_full` is lower than `energy`,
-                // but battery is still charging. What should we do in that case?
-                // As for now, assuming that battery is fully charged, since we can't guess,
-                // how much time left.
-                let energy_left = match self.energy_full() - self.energy() {
-                    value if value.is_sign_positive() => value,
-                    _ => return None,
-                };
-
-                let time_to_full = energy_left / energy_rate;
-                if time_to_full.get::<hour>() > 10.0 {
-                    // Ten hours for charging is too much
-                    None
-                } else {
-                    Some(time_to_full)
-                }
+        time_to_full(
+            self.state(),
+            self.energy(),
+            self.energy_full(),
+            self.energy_rate(),
+            &self.sanitize_policy(),
+        )
+    }
+
+    fn time_to_empty(&self) -> Option<Time> {
+        time_to_empty(
+            self.state(),
+            self.energy(),
+            self.energy_rate(),
+            &self.sanitize_policy(),
+        )
+    }
+}
+
+/// Provenance of the device values, where zeroes are considered to be the missing values,
+/// same as UPower and the most of the platform APIs are reporting them.
+pub(crate) fn missing_values_provenance<T: BatteryDevice>(device: &T) -> DataProvenance {
+    let known = |is_missing: bool| match is_missing {
+        true => Provenance::Defaulted,
+        false => Provenance::Measured,
+    };
+    // Zero rate is a valid one for the idle battery
+    let is_flowing = matches!(device.state(), State::Charging | State::Discharging);
+
+    DataProvenance {
+        state_of_charge: Provenance::Measured,
+        state_of_health: known(
+            device.energy_full().is_zero() || device.energy_full_design().is_zero(),
+        ),
+        // Zero energy is a valid one for the empty battery
+        energy: known(device.energy().is_zero() && device.energy_full().is_zero()),
+        energy_full: known(device.energy_full().is_zero()),
+        energy_full_design: known(device.energy_full_design().is_zero()),
+        energy_rate: known(is_flowing && device.energy_rate().is_zero()),
+        voltage: known(device.voltage().is_zero()),
+    }
+}
+
+/// Default time estimations, also used by the `Estimator` with a smoothed energy rate.
+pub(crate) fn time_to_full(
+    state: State,
+    energy: Energy,
+    energy_full: Energy,
+    energy_rate: Power,
+    policy: &SanitizePolicy,
+) -> Option<Time> {
+    match state {
+        // In some cases energy_rate can be 0 while Charging, for example just after
+        // plugging in the charger. Assume that the battery doesn't have time_to_full in such
+        // cases, to avoid division by zero. See https://github.com/svartalf/rust-battery/pull/5
+        State::Charging if !energy_rate.is_zero() => {
+            // Some drivers might report that `energy_full` is lower than `energy`,
+            // but battery is still charging. What should we do in that case?
+            // As for now, assuming that battery is fully charged, since we can't guess,
+            // how much time left.
+            let energy_left = match energy_full - energy {
+                value if value.is_sign_positive() => value,
+                _ => return None,
+            };
+
+            let time_to_full = energy_left / energy_rate;
+            match policy.max_time_to_full {
+                // Ten hours for charging is too much by default
+                Some(max) if time_to_full > max => None,
+                _ => Some(time_to_full),
             }
-            _ => None,
         }
+        _ => None,
     }
+}
 
-    fn time_to_empty(&self) -> Option<Time> {
-        let energy_rate = self.energy_rate();
-        match self.state() {
-            // In some cases energy_rate can be 0 while Discharging, for example just after
-            // unplugging the charger. Assume that the battery doesn't have time_to_empty in such
-            // cases, to avoid divison by zero. See https://github.com/svartalf/rust-battery/pull/5
-            State::Discharging if !energy_rate.is_zero() => {
-                let time_to_empty = self.energy() / energy_rate;
-                if time_to_empty.get::<day>() > 10.0 {
-                    // Ten days for discharging is too much
-                    None
-                } else {
-                    Some(time_to_empty)
-                }
+pub(crate) fn time_to_empty(
+    state: State,
+    energy: Energy,
+    energy_rate: Power,
+    policy: &SanitizePolicy,
+) -> Option<Time> {
+    match state {
+        // In some cases energy_rate can be 0 while Discharging, for example just after
+        // unplugging the charger. Assume that the battery doesn't have time_to_empty in such
+        // cases, to avoid divison by zero. See https://github.com/svartalf/rust-battery/pull/5
+        State::Discharging if !energy_rate.is_zero() => {
+            let time_to_empty = energy / energy_rate;
+            match policy.max_time_to_empty {
+                // Ten days for discharging is too much by default
+                Some(max) if time_to_empty > max => None,
+                _ => Some(time_to_empty),
             }
-            _ => None,
         }
+        _ => None,
     }
 }
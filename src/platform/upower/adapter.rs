@@ -0,0 +1,54 @@
+use std::vec;
+
+use zbus::blocking::Connection;
+use zbus::zvariant::OwnedObjectPath;
+
+use super::properties::{Kind, Properties};
+use crate::{Adapter, AdapterType, Result};
+
+/// Iterator over the "Line Power" devices.
+pub struct UPowerAdapterIterator {
+    connection: Connection,
+    paths: vec::IntoIter<OwnedObjectPath>,
+}
+
+impl UPowerAdapterIterator {
+    pub fn new(connection: Connection, paths: Vec<OwnedObjectPath>) -> UPowerAdapterIterator {
+        UPowerAdapterIterator {
+            connection,
+            paths: paths.into_iter(),
+        }
+    }
+}
+
+impl Iterator for UPowerAdapterIterator {
+    type Item = Result<Adapter>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let path = self.paths.next()?;
+            let properties = match Properties::fetch(&self.connection, &path) {
+                Ok(properties) => properties,
+                Err(e) => return Some(Err(e)),
+            };
+            if properties.kind() != Kind::LinePower {
+                continue;
+            }
+
+            let name = properties.get_string("NativePath").unwrap_or_else(|| {
+                path.as_str()
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_string()
+            });
+
+            return Some(Ok(Adapter {
+                name,
+                type_: AdapterType::Mains,
+                online: properties.get::<bool>("Online"),
+                ..Adapter::default()
+            }));
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use std::fmt;
+
+use num_traits::identities::Zero;
+
+use zbus::blocking::Connection;
+use zbus::zvariant::OwnedObjectPath;
+
+use super::properties::Properties;
+use crate::platform::traits::*;
+use crate::units::{
+    Bound, ElectricPotential, Energy, Power, Ratio, ThermodynamicTemperature, Time,
+};
+use crate::{
+    CapacityLevel, ChargeThresholds, DataProvenance, DeviceKind, Provenance, Result, Scope, State,
+    Technology,
+};
+
+/// Values which might change with refresh.
+struct InstantData {
+    state_of_charge: Ratio,
+    state_of_health: Option<Ratio>,
+    energy: Energy,
+    energy_full: Energy,
+    energy_full_design: Energy,
+    energy_rate: Power,
+    state: State,
+    voltage: ElectricPotential,
+    temperature: Option<ThermodynamicTemperature>,
+    cycle_count: Option<u32>,
+    capacity_level: Option<CapacityLevel>,
+    time_to_full: Option<Time>,
+    time_to_empty: Option<Time>,
+    charge_thresholds: Option<ChargeThresholds>,
+}
+
+impl InstantData {
+    fn new(properties: &Properties) -> InstantData {
+        let f64_value = |name: &str| properties.get::<f64>(name).unwrap_or_default();
+        // UPower is using zero for unknown values
+        let positive = |name: &str| properties.get::<f64>(name).filter(|value| *value > 0.0);
+        let seconds = |name: &str| {
+            properties
+                .get::<i64>(name)
+                .filter(|value| *value > 0)
+                .map(|value| second!(value))
+        };
+
+        let charge_thresholds = match properties.get::<bool>("ChargeThresholdSupported") {
+            Some(true) => Some(ChargeThresholds {
+                start: properties
+                    .get::<u32>("ChargeStartThreshold")
+                    .map(|value| percent!(value)),
+                end: properties
+                    .get::<u32>("ChargeEndThreshold")
+                    .map(|value| percent!(value)),
+            }),
+            _ => None,
+        };
+
+        InstantData {
+            state_of_charge: percent!(f64_value("Percentage")).into_bounded(),
+            state_of_health: positive("Capacity").map(|value| percent!(value).into_bounded()),
+            energy: watt_hour!(f64_value("Energy")),
+            energy_full: watt_hour!(f64_value("EnergyFull")),
+            energy_full_design: watt_hour!(f64_value("EnergyFullDesign")),
+            // Positive while discharging and negative while charging
+            energy_rate: watt!(f64_value("EnergyRate").abs()),
+            state: properties.state(),
+            voltage: volt!(f64_value("Voltage")),
+            temperature: properties
+                .get::<f64>("Temperature")
+                .filter(|value| *value != 0.0)
+                .map(|value| celsius!(value)),
+            // `-1` if unknown
+            cycle_count: properties
+                .get::<i32>("ChargeCycles")
+                .and_then(|value| u32::try_from(value).ok()),
+            capacity_level: properties.capacity_level(),
+            time_to_full: seconds("TimeToFull"),
+            time_to_empty: seconds("TimeToEmpty"),
+            charge_thresholds,
+        }
+    }
+}
+
+pub struct UPowerDevice {
+    path: OwnedObjectPath,
+    name: String,
+    source: InstantData,
+
+    vendor: Option<String>,
+    model: Option<String>,
+    serial_number: Option<String>,
+    technology: Technology,
+    scope: Scope,
+    device_kind: Option<DeviceKind>,
+}
+
+impl UPowerDevice {
+    pub fn new(path: OwnedObjectPath, properties: &Properties) -> UPowerDevice {
+        let scope = properties.scope().unwrap_or_default();
+        let device_kind = match scope {
+            Scope::Device => Some(properties.device_kind()),
+            _ => None,
+        };
+
+        // Native path is the sysfs device name, same as the sysfs backend uses
+        let name = properties.get_string("NativePath").unwrap_or_else(|| {
+            path.as_str()
+                .rsplit('/')
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        });
+
+        UPowerDevice {
+            path,
+            name,
+            source: InstantData::new(properties),
+            vendor: properties.get_string("Vendor"),
+            model: properties.get_string("Model"),
+            serial_number: properties.get_string("Serial"),
+            technology: properties.technology(),
+            scope,
+            device_kind,
+        }
+    }
+
+    pub fn refresh(&mut self, connection: &Connection) -> Result<()> {
+        let properties = Properties::fetch(connection, &self.path)?;
+        self.source = InstantData::new(&properties);
+
+        Ok(())
+    }
+}
+
+impl BatteryDevice for UPowerDevice {
+    fn state_of_health(&self) -> Ratio {
+        match self.source.state_of_health {
+            Some(value) => value,
+            // Peripherals and batteries without design data are reporting zeroes,
+            // same fallback as for the sysfs devices
+            None if self.energy_full().is_zero() || self.energy_full_design().is_zero() => {
+                percent!(100.0)
+            }
+            None => (self.energy_full() / self.energy_full_design()).into_bounded(),
+        }
+    }
+
+    fn state_of_charge(&self) -> Ratio {
+        self.source.state_of_charge
+    }
+
+    fn energy(&self) -> Energy {
+        self.source.energy
+    }
+
+    fn energy_full(&self) -> Energy {
+        self.source.energy_full
+    }
+
+    fn energy_full_design(&self) -> Energy {
+        self.source.energy_full_design
+    }
+
+    fn energy_rate(&self) -> Power {
+        self.source.energy_rate
+    }
+
+    fn state(&self) -> State {
+        self.source.state
+    }
+
+    fn voltage(&self) -> ElectricPotential {
+        self.source.voltage
+    }
+
+    fn temperature(&self) -> Option<ThermodynamicTemperature> {
+        self.source.temperature
+    }
+
+    fn vendor(&self) -> Option<&str> {
+        self.vendor.as_deref()
+    }
+
+    fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+
+    fn serial_number(&self) -> Option<&str> {
+        self.serial_number.as_deref()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn technology(&self) -> Technology {
+        self.technology
+    }
+
+    fn cycle_count(&self) -> Option<u32> {
+        self.source.cycle_count
+    }
+
+    fn capacity_level(&self) -> Option<CapacityLevel> {
+        self.source.capacity_level
+    }
+
+    fn scope(&self) -> Scope {
+        self.scope
+    }
+
+    fn device_kind(&self) -> Option<DeviceKind> {
+        self.device_kind
+    }
+
+    fn charge_thresholds(&self) -> Option<ChargeThresholds> {
+        self.source.charge_thresholds
+    }
+
+    fn provenance(&self) -> DataProvenance {
+        let mut provenance = missing_values_provenance(self);
+        // Reported by UPower, even if the energy values are missing
+        if self.source.state_of_health.is_some() {
+            provenance.state_of_health = Provenance::Measured;
+        }
+
+        provenance
+    }
+
+    // UPower is already calculating these values with its own heuristics
+
+    fn time_to_full(&self) -> Option<Time> {
+        self.source.time_to_full
+    }
+
+    fn time_to_empty(&self) -> Option<Time> {
+        self.source.time_to_empty
+    }
+}
+
+impl fmt::Debug for UPowerDevice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UPowerDevice")
+            .field("path", &self.path.as_str())
+            .finish()
+    }
+}
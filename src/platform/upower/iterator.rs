@@ -0,0 +1,60 @@
+use std::fmt;
+use std::sync::Arc;
+use std::vec;
+
+use zbus::zvariant::OwnedObjectPath;
+
+use super::properties::Properties;
+use super::{UPowerDevice, UPowerManager};
+use crate::platform::traits::*;
+use crate::{Result, Scope};
+
+pub struct UPowerIterator {
+    manager: Arc<UPowerManager>,
+    paths: vec::IntoIter<OwnedObjectPath>,
+}
+
+impl BatteryIterator for UPowerIterator {
+    type Manager = UPowerManager;
+    type Device = UPowerDevice;
+
+    fn new(manager: Arc<Self::Manager>) -> Result<Self> {
+        let paths = manager.devices()?.into_iter();
+
+        Ok(UPowerIterator { manager, paths })
+    }
+}
+
+impl Iterator for UPowerIterator {
+    type Item = Result<<Self as BatteryIterator>::Device>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let path = self.paths.next()?;
+            let properties = match Properties::fetch(self.manager.connection(), &path) {
+                Ok(properties) => properties,
+                Err(e) => return Some(Err(e)),
+            };
+
+            match properties.scope() {
+                Some(Scope::System) => {}
+                Some(Scope::Device) if self.manager.include_peripherals() => {}
+                _ => continue,
+            }
+
+            return Some(Ok(UPowerDevice::new(path, &properties)));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.paths.len()))
+    }
+}
+
+impl fmt::Debug for UPowerIterator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UPowerIterator")
+            .field("left", &self.paths.len())
+            .finish()
+    }
+}
@@ -0,0 +1,78 @@
+use zbus::blocking::Connection;
+use zbus::proxy;
+use zbus::zvariant::OwnedObjectPath;
+
+use super::adapter::UPowerAdapterIterator;
+use super::{UPowerDevice, UPowerIterator};
+use crate::platform::traits::*;
+use crate::{Adapter, ManagerBuilder, Result};
+
+#[proxy(
+    interface = "org.freedesktop.UPower",
+    default_service = "org.freedesktop.UPower",
+    default_path = "/org/freedesktop/UPower",
+    gen_async = false,
+    blocking_name = "UPowerProxy"
+)]
+trait UPower {
+    fn enumerate_devices(&self) -> zbus::Result<Vec<OwnedObjectPath>>;
+}
+
+#[derive(Debug)]
+pub struct UPowerManager {
+    connection: Connection,
+    include_peripherals: bool,
+}
+
+impl UPowerManager {
+    pub fn with_connection(connection: Connection, include_peripherals: bool) -> UPowerManager {
+        UPowerManager {
+            connection,
+            include_peripherals,
+        }
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    pub fn include_peripherals(&self) -> bool {
+        self.include_peripherals
+    }
+
+    /// Object paths of all the devices known to UPower, including adapters.
+    pub fn devices(&self) -> Result<Vec<OwnedObjectPath>> {
+        let proxy = UPowerProxy::new(&self.connection)?;
+
+        Ok(proxy.enumerate_devices()?)
+    }
+}
+
+impl BatteryManager for UPowerManager {
+    type Iterator = UPowerIterator;
+
+    fn new() -> Result<Self> {
+        let connection = Connection::system()?;
+
+        Ok(Self::with_connection(connection, false))
+    }
+
+    fn with_builder(builder: &ManagerBuilder) -> Result<Self> {
+        let connection = Connection::system()?;
+
+        Ok(Self::with_connection(
+            connection,
+            builder.get_include_peripherals(),
+        ))
+    }
+
+    fn refresh(&self, device: &mut UPowerDevice) -> Result<()> {
+        device.refresh(&self.connection)
+    }
+
+    fn adapters(&self) -> Result<Box<dyn Iterator<Item = Result<Adapter>> + Send>> {
+        let iterator = UPowerAdapterIterator::new(self.connection.clone(), self.devices()?);
+
+        Ok(Box::new(iterator))
+    }
+}
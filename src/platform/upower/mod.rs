@@ -0,0 +1,16 @@
+//! Batteries information from the UPower daemon over the system D-Bus.
+//!
+//! Used inside of sandboxes, where `/sys/class/power_supply` is not available.
+
+mod adapter;
+mod device;
+mod iterator;
+mod manager;
+mod properties;
+
+pub use self::device::UPowerDevice;
+pub use self::iterator::UPowerIterator;
+pub use self::manager::UPowerManager;
+
+#[cfg(test)]
+mod tests;
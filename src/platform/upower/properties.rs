@@ -0,0 +1,146 @@
+//! Properties of the `org.freedesktop.UPower.Device` interface,
+//! see https://upower.freedesktop.org/docs/Device.html for details.
+
+use std::collections::HashMap;
+
+use zbus::blocking::fdo::PropertiesProxy;
+use zbus::blocking::Connection;
+use zbus::names::InterfaceName;
+use zbus::zvariant::{ObjectPath, OwnedValue};
+
+use crate::{CapacityLevel, DeviceKind, Result, Scope, State, Technology};
+
+pub static SERVICE: &str = "org.freedesktop.UPower";
+pub static DEVICE_INTERFACE: &str = "org.freedesktop.UPower.Device";
+
+/// Subset of the `UpDeviceKind` values, which are handled differently.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Kind {
+    Unknown,
+    LinePower,
+    Battery,
+    Ups,
+    Monitor,
+    Mouse,
+    Keyboard,
+    Phone,
+    GamingInput,
+    Headset,
+    Other,
+}
+
+impl From<u32> for Kind {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Kind::LinePower,
+            2 => Kind::Battery,
+            3 => Kind::Ups,
+            4 => Kind::Monitor,
+            5 => Kind::Mouse,
+            6 => Kind::Keyboard,
+            8 => Kind::Phone,
+            12 => Kind::GamingInput,
+            17 | 19 => Kind::Headset,
+            0 => Kind::Unknown,
+            _ => Kind::Other,
+        }
+    }
+}
+
+/// All the device properties, fetched at once with the `GetAll` call.
+pub struct Properties(HashMap<String, OwnedValue>);
+
+impl Properties {
+    pub fn fetch(connection: &Connection, path: &ObjectPath<'_>) -> Result<Properties> {
+        let proxy = PropertiesProxy::builder(connection)
+            .destination(SERVICE)?
+            .path(path)?
+            .build()?;
+        let properties =
+            proxy.get_all(InterfaceName::from_static_str_unchecked(DEVICE_INTERFACE))?;
+
+        Ok(Properties(properties))
+    }
+
+    /// Returns `None` if property is missing or has an unexpected type.
+    pub fn get<'a, T>(&'a self, name: &str) -> Option<T>
+    where
+        T: TryFrom<&'a OwnedValue>,
+    {
+        self.0.get(name).and_then(|value| T::try_from(value).ok())
+    }
+
+    /// UPower reports missing string values as empty strings.
+    pub fn get_string(&self, name: &str) -> Option<String> {
+        match self.get::<&str>(name).map(str::trim) {
+            Some(value) if !value.is_empty() => Some(value.to_string()),
+            _ => None,
+        }
+    }
+
+    pub fn kind(&self) -> Kind {
+        Kind::from(self.get::<u32>("Type").unwrap_or_default())
+    }
+
+    /// Returns `None` if device is not a battery.
+    pub fn scope(&self) -> Option<Scope> {
+        if !self.get::<bool>("IsPresent").unwrap_or(true) {
+            return None;
+        }
+
+        match self.kind() {
+            Kind::Unknown | Kind::LinePower | Kind::Ups | Kind::Monitor => None,
+            Kind::Battery if self.get::<bool>("PowerSupply").unwrap_or(false) => {
+                Some(Scope::System)
+            }
+            _ => Some(Scope::Device),
+        }
+    }
+
+    pub fn device_kind(&self) -> DeviceKind {
+        match self.kind() {
+            Kind::Mouse => DeviceKind::Mouse,
+            Kind::Keyboard => DeviceKind::Keyboard,
+            Kind::Phone => DeviceKind::Phone,
+            Kind::GamingInput => DeviceKind::Gamepad,
+            Kind::Headset => DeviceKind::Headset,
+            _ => DeviceKind::Unknown,
+        }
+    }
+
+    pub fn state(&self) -> State {
+        match self.get::<u32>("State") {
+            Some(1) => State::Charging,
+            Some(2) => State::Discharging,
+            Some(3) => State::Empty,
+            Some(4) => State::Full,
+            // "Pending charge" is reported for the kernel "Not charging" status
+            Some(5) => State::Paused,
+            _ => State::Unknown,
+        }
+    }
+
+    pub fn technology(&self) -> Technology {
+        match self.get::<u32>("Technology") {
+            Some(1) => Technology::LithiumIon,
+            Some(2) => Technology::LithiumPolymer,
+            Some(3) => Technology::LithiumIronPhosphate,
+            Some(4) => Technology::LeadAcid,
+            Some(5) => Technology::NickelCadmium,
+            Some(6) => Technology::NickelMetalHydride,
+            _ => Technology::Unknown,
+        }
+    }
+
+    pub fn capacity_level(&self) -> Option<CapacityLevel> {
+        match self.get::<u32>("BatteryLevel") {
+            Some(3) => Some(CapacityLevel::Low),
+            Some(4) => Some(CapacityLevel::Critical),
+            Some(6) => Some(CapacityLevel::Normal),
+            Some(7) => Some(CapacityLevel::High),
+            Some(8) => Some(CapacityLevel::Full),
+            // "Unknown" and "None" are meaning that device reports percentage instead
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use std::io;
+
+use approx::assert_abs_diff_eq;
+
+use super::{device_path, Bus, MockDevice};
+use crate::units::electric_potential::volt;
+use crate::units::energy::watt_hour;
+use crate::units::power::watt;
+use crate::units::ratio::percent;
+use crate::units::thermodynamic_temperature::degree_celsius;
+use crate::units::time::hour;
+use crate::{AdapterType, DeviceKind, Provenance, Scope, State, Technology};
+
+fn devices() -> Vec<(&'static str, MockDevice)> {
+    vec![
+        (
+            "battery_BAT0",
+            MockDevice {
+                native_path: "BAT0".into(),
+                vendor: "SMP".into(),
+                model: "5B10W13930".into(),
+                kind: 2,
+                power_supply: true,
+                state: 2,
+                technology: 2,
+                percentage: 80.0,
+                capacity: 92.5,
+                energy: 40.0,
+                energy_full: 50.0,
+                energy_full_design: 54.0,
+                energy_rate: 10.0,
+                voltage: 12.5,
+                charge_cycles: 112,
+                time_to_empty: 4 * 60 * 60,
+                ..MockDevice::default()
+            },
+        ),
+        (
+            "line_power_AC",
+            MockDevice {
+                native_path: "AC".into(),
+                kind: 1,
+                power_supply: true,
+                online: false,
+                ..MockDevice::default()
+            },
+        ),
+        (
+            "mouse_hidpp_battery_0",
+            MockDevice {
+                native_path: "hidpp_battery_0".into(),
+                model: "MX Master 3".into(),
+                kind: 5,
+                state: 2,
+                percentage: 55.0,
+                // Level-only device
+                battery_level: 6,
+                charge_cycles: -1,
+                temperature: 0.0,
+                ..MockDevice::default()
+            },
+        ),
+    ]
+}
+
+#[test]
+fn test_upower_batteries() {
+    let Some(bus) = Bus::start() else { return };
+    let _service = bus.serve(devices());
+    let manager = bus.manager(false);
+
+    let batteries = manager
+        .batteries()
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(batteries.len(), 1);
+
+    let battery = &batteries[0];
+    assert_eq!(battery.vendor(), Some("SMP"));
+    assert_eq!(battery.model(), Some("5B10W13930"));
+    assert_eq!(battery.serial_number(), None);
+    assert_eq!(battery.scope(), Scope::System);
+    assert_eq!(battery.state(), State::Discharging);
+    assert_eq!(battery.technology(), Technology::LithiumPolymer);
+    assert_eq!(battery.cycle_count(), Some(112));
+    assert_eq!(battery.temperature(), None);
+    assert_abs_diff_eq!(
+        battery.state_of_charge().get::<percent>(),
+        80.0,
+        epsilon = 0.001
+    );
+    assert_abs_diff_eq!(
+        battery.state_of_health().get::<percent>(),
+        92.5,
+        epsilon = 0.001
+    );
+    assert_abs_diff_eq!(battery.energy().get::<watt_hour>(), 40.0, epsilon = 0.001);
+    assert_abs_diff_eq!(
+        battery.energy_full().get::<watt_hour>(),
+        50.0,
+        epsilon = 0.001
+    );
+    assert_abs_diff_eq!(
+        battery.energy_full_design().get::<watt_hour>(),
+        54.0,
+        epsilon = 0.001
+    );
+    assert_abs_diff_eq!(battery.energy_rate().get::<watt>(), 10.0, epsilon = 0.001);
+    assert_abs_diff_eq!(battery.voltage().get::<volt>(), 12.5, epsilon = 0.001);
+    assert_abs_diff_eq!(
+        battery.time_to_empty().unwrap().get::<hour>(),
+        4.0,
+        epsilon = 0.001
+    );
+    assert_eq!(battery.time_to_full(), None);
+}
+
+#[test]
+fn test_upower_peripherals() {
+    let Some(bus) = Bus::start() else { return };
+    let _service = bus.serve(devices());
+    let manager = bus.manager(true);
+
+    let mouse = manager
+        .batteries()
+        .unwrap()
+        .map(Result::unwrap)
+        .find(|battery| battery.scope() == Scope::Device)
+        .unwrap();
+
+    assert_eq!(mouse.model(), Some("MX Master 3"));
+    assert_eq!(mouse.device_kind(), Some(DeviceKind::Mouse));
+    assert_eq!(mouse.capacity_level(), Some(crate::CapacityLevel::Normal));
+    assert_eq!(mouse.cycle_count(), None);
+    assert_abs_diff_eq!(
+        mouse.state_of_charge().get::<percent>(),
+        55.0,
+        epsilon = 0.001
+    );
+    // Neither capacity, nor energy values are reported
+    assert_abs_diff_eq!(
+        mouse.state_of_health().get::<percent>(),
+        100.0,
+        epsilon = 0.001
+    );
+}
+
+#[test]
+fn test_upower_missing_values() {
+    let Some(bus) = Bus::start() else { return };
+    let _service = bus.serve(devices());
+    let manager = bus.manager(true);
+    let batteries = manager
+        .batteries()
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+
+    let battery = batteries
+        .iter()
+        .find(|battery| battery.scope() == Scope::System)
+        .unwrap();
+    assert_abs_diff_eq!(
+        battery.try_energy_full_design().unwrap().get::<watt_hour>(),
+        54.0,
+        epsilon = 0.001
+    );
+    assert_abs_diff_eq!(
+        battery.try_voltage().unwrap().get::<volt>(),
+        12.5,
+        epsilon = 0.001
+    );
+    assert_abs_diff_eq!(
+        battery.try_energy_rate().unwrap().get::<watt>(),
+        10.0,
+        epsilon = 0.001
+    );
+    assert_abs_diff_eq!(
+        battery.try_state_of_health().unwrap().get::<percent>(),
+        92.5,
+        epsilon = 0.001
+    );
+
+    // UPower reports zeroes for the values missing on the level-only devices
+    let mouse = batteries
+        .iter()
+        .find(|battery| battery.scope() == Scope::Device)
+        .unwrap();
+    assert_eq!(mouse.provenance().voltage(), Provenance::Defaulted);
+    assert_eq!(mouse.try_energy(), None);
+    assert_eq!(mouse.try_energy_full(), None);
+    assert_eq!(mouse.try_energy_full_design(), None);
+    assert_eq!(mouse.try_energy_rate(), None);
+    assert_eq!(mouse.try_voltage(), None);
+    assert_eq!(mouse.try_state_of_health(), None);
+}
+
+#[test]
+fn test_upower_adapters() {
+    let Some(bus) = Bus::start() else { return };
+    let _service = bus.serve(devices());
+    let manager = bus.manager(false);
+
+    let adapters = manager
+        .adapters()
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(adapters.len(), 1);
+    assert_eq!(adapters[0].name(), "AC");
+    assert_eq!(adapters[0].type_(), AdapterType::Mains);
+    assert_eq!(adapters[0].online(), Some(false));
+}
+
+#[test]
+fn test_upower_refresh() {
+    let Some(bus) = Bus::start() else { return };
+    let service = bus.serve(devices());
+    let manager = bus.manager(false);
+    let mut battery = manager.batteries().unwrap().next().unwrap().unwrap();
+
+    let path = device_path("battery_BAT0");
+    {
+        let device = service
+            .object_server()
+            .interface::<_, MockDevice>(&path)
+            .unwrap();
+        let mut device = device.get_mut();
+        device.state = 1;
+        device.percentage = 81.0;
+        device.temperature = 31.5;
+    }
+    manager.refresh(&mut battery).unwrap();
+
+    assert_eq!(battery.state(), State::Charging);
+    assert_abs_diff_eq!(
+        battery.state_of_charge().get::<percent>(),
+        81.0,
+        epsilon = 0.001
+    );
+    assert_abs_diff_eq!(battery.temperature().unwrap().get::<degree_celsius>(), 31.5);
+
+    // Battery was unplugged
+    service
+        .object_server()
+        .remove::<MockDevice, _>(&path)
+        .unwrap();
+    let e = manager.refresh(&mut battery).unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::NotFound);
+    assert!(e.is_device_removed());
+}
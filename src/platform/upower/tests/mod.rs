@@ -0,0 +1,267 @@
+//! Tests are running against the mock UPower service,
+//! registered on the private `dbus-daemon` instance.
+//!
+//! They are skipped if `dbus-daemon` executable is not available.
+
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::Arc;
+
+use zbus::blocking::{connection, Connection};
+use zbus::interface;
+use zbus::zvariant::OwnedObjectPath;
+
+use super::UPowerManager;
+use crate::platform::Manager as PlatformManager;
+use crate::Manager;
+
+mod devices;
+
+static BUS_CONFIG: &str = r#"<!DOCTYPE busconfig PUBLIC "-//freedesktop//DTD D-Bus Bus Configuration 1.0//EN"
+ "http://www.freedesktop.org/standards/dbus/1.0/busconfig.dtd">
+<busconfig>
+  <type>session</type>
+  <listen>unix:path={path}</listen>
+  <auth>EXTERNAL</auth>
+  <policy context="default">
+    <allow send_destination="*"/>
+    <allow receive_sender="*"/>
+    <allow own="*"/>
+  </policy>
+</busconfig>"#;
+
+/// Private `dbus-daemon` instance, killed on drop.
+struct Bus {
+    daemon: Child,
+    address: String,
+    _dir: tempfile::TempDir,
+}
+
+impl Bus {
+    fn start() -> Option<Bus> {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("bus.conf");
+        let socket = dir.path().join("bus");
+        std::fs::write(
+            &config,
+            BUS_CONFIG.replace("{path}", &socket.to_string_lossy()),
+        )
+        .unwrap();
+
+        let mut daemon = match Command::new("dbus-daemon")
+            .arg("--nofork")
+            .arg("--print-address")
+            .arg(format!("--config-file={}", config.display()))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(daemon) => daemon,
+            Err(e) => {
+                eprintln!("Unable to start dbus-daemon, skipping the test: {}", e);
+                return None;
+            }
+        };
+
+        // Address is printed when daemon is ready to accept connections
+        let mut address = String::new();
+        BufReader::new(daemon.stdout.take().unwrap())
+            .read_line(&mut address)
+            .unwrap();
+
+        Some(Bus {
+            daemon,
+            address: address.trim().to_string(),
+            _dir: dir,
+        })
+    }
+
+    fn connect(&self) -> Connection {
+        connection::Builder::address(self.address.as_str())
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    /// Registers mock UPower service with the `devices` on this bus.
+    fn serve(&self, devices: Vec<(&str, MockDevice)>) -> Connection {
+        let paths = devices.iter().map(|(name, _)| device_path(name)).collect();
+        let mut builder = connection::Builder::address(self.address.as_str())
+            .unwrap()
+            .name("org.freedesktop.UPower")
+            .unwrap()
+            .serve_at("/org/freedesktop/UPower", MockUPower { paths })
+            .unwrap();
+        for (name, device) in devices {
+            builder = builder.serve_at(device_path(name), device).unwrap();
+        }
+
+        builder.build().unwrap()
+    }
+
+    fn manager(&self, include_peripherals: bool) -> Manager {
+        let inner = UPowerManager::with_connection(self.connect(), include_peripherals);
+
+        Manager::from(PlatformManager::UPower(Arc::new(inner)))
+    }
+}
+
+impl Drop for Bus {
+    fn drop(&mut self) {
+        let _ = self.daemon.kill();
+        let _ = self.daemon.wait();
+    }
+}
+
+fn device_path(name: &str) -> OwnedObjectPath {
+    OwnedObjectPath::try_from(format!("/org/freedesktop/UPower/devices/{}", name)).unwrap()
+}
+
+struct MockUPower {
+    paths: Vec<OwnedObjectPath>,
+}
+
+#[interface(name = "org.freedesktop.UPower")]
+impl MockUPower {
+    fn enumerate_devices(&self) -> Vec<OwnedObjectPath> {
+        self.paths.clone()
+    }
+}
+
+/// Subset of the `org.freedesktop.UPower.Device` properties.
+#[derive(Default)]
+struct MockDevice {
+    native_path: String,
+    vendor: String,
+    model: String,
+    kind: u32,
+    power_supply: bool,
+    online: bool,
+    state: u32,
+    technology: u32,
+    percentage: f64,
+    capacity: f64,
+    energy: f64,
+    energy_full: f64,
+    energy_full_design: f64,
+    energy_rate: f64,
+    voltage: f64,
+    temperature: f64,
+    charge_cycles: i32,
+    time_to_empty: i64,
+    time_to_full: i64,
+    battery_level: u32,
+}
+
+#[interface(name = "org.freedesktop.UPower.Device")]
+impl MockDevice {
+    #[zbus(property)]
+    fn native_path(&self) -> String {
+        self.native_path.clone()
+    }
+
+    #[zbus(property)]
+    fn vendor(&self) -> String {
+        self.vendor.clone()
+    }
+
+    #[zbus(property)]
+    fn model(&self) -> String {
+        self.model.clone()
+    }
+
+    #[zbus(property)]
+    fn serial(&self) -> String {
+        String::new()
+    }
+
+    #[zbus(property, name = "Type")]
+    fn kind(&self) -> u32 {
+        self.kind
+    }
+
+    #[zbus(property)]
+    fn power_supply(&self) -> bool {
+        self.power_supply
+    }
+
+    #[zbus(property)]
+    fn is_present(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn online(&self) -> bool {
+        self.online
+    }
+
+    #[zbus(property)]
+    fn state(&self) -> u32 {
+        self.state
+    }
+
+    #[zbus(property)]
+    fn technology(&self) -> u32 {
+        self.technology
+    }
+
+    #[zbus(property)]
+    fn percentage(&self) -> f64 {
+        self.percentage
+    }
+
+    #[zbus(property)]
+    fn capacity(&self) -> f64 {
+        self.capacity
+    }
+
+    #[zbus(property)]
+    fn energy(&self) -> f64 {
+        self.energy
+    }
+
+    #[zbus(property)]
+    fn energy_full(&self) -> f64 {
+        self.energy_full
+    }
+
+    #[zbus(property)]
+    fn energy_full_design(&self) -> f64 {
+        self.energy_full_design
+    }
+
+    #[zbus(property)]
+    fn energy_rate(&self) -> f64 {
+        self.energy_rate
+    }
+
+    #[zbus(property)]
+    fn voltage(&self) -> f64 {
+        self.voltage
+    }
+
+    #[zbus(property)]
+    fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    #[zbus(property)]
+    fn charge_cycles(&self) -> i32 {
+        self.charge_cycles
+    }
+
+    #[zbus(property)]
+    fn time_to_empty(&self) -> i64 {
+        self.time_to_empty
+    }
+
+    #[zbus(property)]
+    fn time_to_full(&self) -> i64 {
+        self.time_to_full
+    }
+
+    #[zbus(property)]
+    fn battery_level(&self) -> u32 {
+        self.battery_level
+    }
+}
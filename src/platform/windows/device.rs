@@ -150,6 +150,10 @@ impl BatteryDevice for PowerDevice {
         self.serial_number.as_ref().map(AsRef::as_ref)
     }
 
+    fn name(&self) -> String {
+        self.tag.BatteryTag.to_string()
+    }
+
     fn technology(&self) -> Technology {
         self.technology
     }
@@ -0,0 +1,167 @@
+use std::fmt;
+use std::str;
+
+use crate::units::{ElectricCurrent, ElectricPotential};
+use crate::Error;
+
+/// Possible power supply types, which are not batteries.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+#[derive(Default)]
+pub enum AdapterType {
+    #[default]
+    Unknown,
+    /// AC adapter.
+    Mains,
+    /// USB port or USB charger.
+    Usb,
+    /// Uninterruptible power supply.
+    Ups,
+}
+
+impl fmt::Display for AdapterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            AdapterType::Unknown => "unknown",
+            AdapterType::Mains => "mains",
+            AdapterType::Usb => "usb",
+            AdapterType::Ups => "ups",
+        };
+
+        write!(f, "{}", display)
+    }
+}
+
+/// Possible USB power supply types.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+#[derive(Default)]
+pub enum UsbType {
+    #[default]
+    Unknown,
+    /// Standard Downstream Port
+    Sdp,
+    /// Dedicated Charging Port
+    Dcp,
+    /// Charging Downstream Port
+    Cdp,
+    /// Accessory Charger Adapter
+    Aca,
+    /// Type-C
+    C,
+    /// Power Delivery
+    Pd,
+    /// Power Delivery Dual Role Port
+    PdDrp,
+    /// Power Delivery Programmable Power Supply
+    PdPps,
+    /// Apple charging method
+    AppleBrickId,
+}
+
+impl str::FromStr for UsbType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let usb_type = match s {
+            _ if s.eq_ignore_ascii_case("SDP") => UsbType::Sdp,
+            _ if s.eq_ignore_ascii_case("DCP") => UsbType::Dcp,
+            _ if s.eq_ignore_ascii_case("CDP") => UsbType::Cdp,
+            _ if s.eq_ignore_ascii_case("ACA") => UsbType::Aca,
+            _ if s.eq_ignore_ascii_case("C") => UsbType::C,
+            _ if s.eq_ignore_ascii_case("PD") => UsbType::Pd,
+            _ if s.eq_ignore_ascii_case("PD_DRP") => UsbType::PdDrp,
+            _ if s.eq_ignore_ascii_case("PD_PPS") => UsbType::PdPps,
+            _ if s.eq_ignore_ascii_case("BrickID") => UsbType::AppleBrickId,
+            _ => UsbType::Unknown,
+        };
+
+        Ok(usb_type)
+    }
+}
+
+impl fmt::Display for UsbType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            UsbType::Unknown => "unknown",
+            UsbType::Sdp => "sdp",
+            UsbType::Dcp => "dcp",
+            UsbType::Cdp => "cdp",
+            UsbType::Aca => "aca",
+            UsbType::C => "c",
+            UsbType::Pd => "pd",
+            UsbType::PdDrp => "pd-drp",
+            UsbType::PdPps => "pd-pps",
+            UsbType::AppleBrickId => "apple-brick-id",
+        };
+
+        write!(f, "{}", display)
+    }
+}
+
+/// Power supply information, which is not a battery:
+/// AC adapter, USB port or UPS.
+///
+/// Unlike the [Battery](struct.Battery.html), adapter values are not refreshable,
+/// call the [Manager::adapters](struct.Manager.html#method.adapters) again to get new ones.
+#[derive(Debug, Clone, Default)]
+pub struct Adapter {
+    pub(crate) name: String,
+    pub(crate) type_: AdapterType,
+    pub(crate) online: Option<bool>,
+    pub(crate) usb_type: Option<UsbType>,
+    pub(crate) voltage_max: Option<ElectricPotential>,
+    pub(crate) current_max: Option<ElectricCurrent>,
+    pub(crate) input_current_limit: Option<ElectricCurrent>,
+}
+
+impl Adapter {
+    /// Power supply name as reported by the OS (ex. `AC` or `ucsi-source-psy-USBC000:001`).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Power supply type.
+    ///
+    /// See [AdapterType](enum.AdapterType.html) enum for possible values.
+    pub fn type_(&self) -> AdapterType {
+        self.type_
+    }
+
+    /// Is the power supply connected and providing the power now.
+    pub fn online(&self) -> Option<bool> {
+        self.online
+    }
+
+    /// Currently negotiated USB power supply type.
+    pub fn usb_type(&self) -> Option<UsbType> {
+        self.usb_type
+    }
+
+    /// Maximum voltage the power supply can provide.
+    pub fn voltage_max(&self) -> Option<ElectricPotential> {
+        self.voltage_max
+    }
+
+    /// Maximum current the power supply can provide.
+    pub fn current_max(&self) -> Option<ElectricCurrent> {
+        self.current_max
+    }
+
+    /// Current limit set for the power supply input.
+    pub fn input_current_limit(&self) -> Option<ElectricCurrent> {
+        self.input_current_limit
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt;
+
+/// Source of the batteries information on Linux.
+///
+/// See [ManagerBuilder::backend](struct.ManagerBuilder.html#method.backend) method.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[non_exhaustive]
+#[derive(Default)]
+pub enum Backend {
+    /// Use the sysfs if it is accessible, UPower daemon otherwise.
+    #[default]
+    Auto,
+    /// Read the `/sys/class/power_supply` directory
+    /// (or the one passed to the [ManagerBuilder::sysfs_root](struct.ManagerBuilder.html#method.sysfs_root) method).
+    SysFs,
+    /// Query the `org.freedesktop.UPower` service on the system D-Bus.
+    ///
+    /// Useful inside of sandboxes (Flatpak, Snap), where sysfs is not available.
+    UPower,
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            Backend::Auto => "auto",
+            Backend::SysFs => "sysfs",
+            Backend::UPower => "upower",
+        };
+
+        write!(f, "{}", display)
+    }
+}
@@ -1,10 +1,19 @@
+use std::collections::BTreeMap;
 use std::fmt;
 use std::ops::{Deref, DerefMut};
+use std::time::SystemTime;
 
 use crate::platform::traits::*;
 use crate::platform::Device;
-use crate::units::{ElectricPotential, Energy, Power, Ratio, ThermodynamicTemperature, Time};
-use crate::{State, Technology};
+use crate::units::{
+    ElectricCharge, ElectricCurrent, ElectricPotential, Energy, Power, Ratio,
+    ThermodynamicTemperature, Time,
+};
+use crate::{
+    BatteryId, BatterySnapshot, CapacityLevel, ChargeBehaviour, ChargeThresholds, ChargeType,
+    DataProvenance, DesignVoltageSource, DeviceKind, Health, Identity, ManufactureDate, PowerFlow,
+    Provenance, Result, Scope, State, Technology,
+};
 
 /// Battery instant information representation.
 ///
@@ -42,6 +51,15 @@ impl Battery {
         self.0.state_of_charge()
     }
 
+    /// Coarse battery capacity level.
+    ///
+    /// Some devices are reporting this level only, in that case
+    /// [Battery::state_of_charge](#method.state_of_charge) method returns
+    /// the [representative value](enum.CapacityLevel.html#method.state_of_charge) for it.
+    pub fn capacity_level(&self) -> Option<CapacityLevel> {
+        self.0.capacity_level()
+    }
+
     /// Amount of energy currently available in the battery.
     pub fn energy(&self) -> Energy {
         self.0.energy()
@@ -58,15 +76,173 @@ impl Battery {
     }
 
     /// Amount of energy being drained from the battery.
+    ///
+    /// On Linux, if the driver does not report it, the rate is calculated
+    /// from the energy changes between the [refreshes](struct.Manager.html#method.refresh),
+    /// so it stays zero until there are enough of them.
     pub fn energy_rate(&self) -> Power {
         self.0.energy_rate()
     }
 
+    /// Direction and amount of the power flowing in or out of the battery.
+    ///
+    /// Direction might be unknown if the battery state is not reported,
+    /// in which case the flow is `Idle` with a non-zero [energy rate](#method.energy_rate),
+    /// see [PowerFlow](enum.PowerFlow.html) for details.
+    ///
+    /// See [PowerFlow::power](enum.PowerFlow.html#method.power) for a signed value,
+    /// which is useful for plotting the net power flow.
+    pub fn power_flow(&self) -> PowerFlow {
+        self.0.power_flow()
+    }
+
     /// Battery voltage.
     pub fn voltage(&self) -> ElectricPotential {
         self.0.voltage()
     }
 
+    /// Electric current flowing in or out of the battery, as reported by the device.
+    ///
+    /// Unlike the [Battery::energy_rate](#method.energy_rate), it is not calculated
+    /// from the other values and is `None` if device does not report it.
+    ///
+    /// Currently reported on Linux and FreeBSD only.
+    pub fn current(&self) -> Option<ElectricCurrent> {
+        self.0.current()
+    }
+
+    /// Amount of charge currently available in the battery.
+    ///
+    /// Devices are reporting either the charge or the energy values natively,
+    /// [Battery::energy](#method.energy) is calculated from this value
+    /// with the battery design voltage for the former ones.\
+    /// Same as for the [Battery::current](#method.current), this value is
+    /// `None` if device does not report it.
+    pub fn charge(&self) -> Option<ElectricCharge> {
+        self.0.charge()
+    }
+
+    /// Amount of charge in the battery when it's considered full.
+    ///
+    /// See [Battery::charge](#method.charge) for details.
+    pub fn charge_full(&self) -> Option<ElectricCharge> {
+        self.0.charge_full()
+    }
+
+    /// Amount of charge the battery is designed to hold when it's considered full.
+    ///
+    /// See [Battery::charge](#method.charge) for details.
+    pub fn charge_full_design(&self) -> Option<ElectricCharge> {
+        self.0.charge_full_design()
+    }
+
+    /// Coulomb counter value of the battery fuel gauge.
+    ///
+    /// Reported by the fuel gauges on phones and embedded devices, Linux only.
+    pub fn charge_counter(&self) -> Option<ElectricCharge> {
+        self.0.charge_counter()
+    }
+
+    /// Minimum design voltage of the battery.
+    ///
+    /// Currently reported on Linux only.
+    pub fn voltage_min_design(&self) -> Option<ElectricPotential> {
+        self.0.voltage_min_design()
+    }
+
+    /// Maximum design voltage of the battery.
+    ///
+    /// Currently reported on Linux only.
+    pub fn voltage_max_design(&self) -> Option<ElectricPotential> {
+        self.0.voltage_max_design()
+    }
+
+    /// Open-circuit voltage of the battery.
+    ///
+    /// Usually reported by the fuel gauges, which are estimating
+    /// the state of charge from it. Currently reported on Linux only.
+    pub fn voltage_ocv(&self) -> Option<ElectricPotential> {
+        self.0.voltage_ocv()
+    }
+
+    /// Average voltage of the battery.
+    ///
+    /// Currently reported on Linux only.
+    pub fn voltage_avg(&self) -> Option<ElectricPotential> {
+        self.0.voltage_avg()
+    }
+
+    /// Voltage used to convert the charge values reported by the device into the energy ones.
+    ///
+    /// Is `None` if device reports energy values natively and no conversion was made.
+    /// Note that if there is no design voltage available, the instantaneous one is used,
+    /// which makes the [Battery::energy](#method.energy) and related values less accurate.
+    pub fn design_voltage_source(&self) -> Option<DesignVoltageSource> {
+        self.0.design_voltage_source()
+    }
+
+    /// Same as the [Battery::energy](#method.energy), but returns `None`
+    /// if the device does not report the value and there is no way to calculate it,
+    /// instead of a fallback value.
+    ///
+    /// See [Battery::provenance](#method.provenance) for details.
+    pub fn try_energy(&self) -> Option<Energy> {
+        known(self.provenance().energy(), self.energy())
+    }
+
+    /// Same as the [Battery::energy_full](#method.energy_full), but returns `None`
+    /// for the unknown value.
+    pub fn try_energy_full(&self) -> Option<Energy> {
+        known(self.provenance().energy_full(), self.energy_full())
+    }
+
+    /// Same as the [Battery::energy_full_design](#method.energy_full_design), but returns `None`
+    /// for the unknown value instead of zero.
+    pub fn try_energy_full_design(&self) -> Option<Energy> {
+        known(
+            self.provenance().energy_full_design(),
+            self.energy_full_design(),
+        )
+    }
+
+    /// Same as the [Battery::energy_rate](#method.energy_rate), but returns `None`
+    /// for the unknown value instead of zero.
+    pub fn try_energy_rate(&self) -> Option<Power> {
+        known(self.provenance().energy_rate(), self.energy_rate())
+    }
+
+    /// Same as the [Battery::voltage](#method.voltage), but returns `None`
+    /// for the unknown value instead of zero.
+    pub fn try_voltage(&self) -> Option<ElectricPotential> {
+        known(self.provenance().voltage(), self.voltage())
+    }
+
+    /// Same as the [Battery::state_of_health](#method.state_of_health), but returns `None`
+    /// if the full or design capacity is unknown.
+    pub fn try_state_of_health(&self) -> Option<Ratio> {
+        known(self.provenance().state_of_health(), self.state_of_health())
+    }
+
+    /// Tells which of the battery values are reported by the device
+    /// and which are calculated from the other values.
+    ///
+    /// Devices are not required to report all the values,
+    /// so some of them might be derived from the charge or capacity values,
+    /// or even be a fallback value.
+    /// Values calculated by the crate are reported on Linux and FreeBSD only,
+    /// on the other platforms values are considered measured,
+    /// unless they are reported as zeroes, which is how these platforms are marking the missing ones.
+    pub fn provenance(&self) -> DataProvenance {
+        self.0.provenance()
+    }
+
+    /// Error margin of the state of charge value, as reported by the fuel gauge.
+    ///
+    /// Currently reported on Linux only.
+    pub fn capacity_error_margin(&self) -> Option<Ratio> {
+        self.0.capacity_error_margin()
+    }
+
     /// Gets battery state of health.
     ///
     /// The *State of Health* (or *SOH*) is an indication of the point
@@ -97,6 +273,16 @@ impl Battery {
         self.0.technology()
     }
 
+    /// Battery health status, as reported by the device firmware.
+    ///
+    /// Unlike the [Battery::state_of_health](#method.state_of_health), which is calculated
+    /// from the battery capacity, this value indicates battery faults detected by the device itself.
+    ///
+    /// See [Health](enum.Health.html) enum for possible values.
+    pub fn health(&self) -> Option<Health> {
+        self.0.health()
+    }
+
     /// Battery temperature.
     pub fn temperature(&self) -> Option<ThermodynamicTemperature> {
         self.0.temperature()
@@ -122,10 +308,126 @@ impl Battery {
         self.0.serial_number()
     }
 
+    /// Battery manufacture date.
+    ///
+    /// Currently reported on Linux only, by the drivers exposing it
+    /// (mostly smart batteries and fuel gauges).
+    pub fn manufacture_date(&self) -> Option<ManufactureDate> {
+        self.0.manufacture_date()
+    }
+
+    /// Battery identifier, which is stable across the enumerations,
+    /// see [BatteryId](struct.BatteryId.html) for details.
+    ///
+    /// Batteries with the same identifier can be fetched directly
+    /// with the [Manager::battery](struct.Manager.html#method.battery) method.
+    pub fn id(&self) -> BatteryId {
+        self.0.id()
+    }
+
+    /// All the battery identification attributes, including the platform-specific ones.
+    ///
+    /// See [Identity](struct.Identity.html) for available attributes.
+    pub fn identity(&self) -> Identity {
+        self.0.identity()
+    }
+
+    /// Battery power topology.
+    ///
+    /// Batteries with the [Scope::Device](enum.Scope.html#variant.Device) scope are powering
+    /// some peripheral device and are enumerated only if requested
+    /// with the [ManagerBuilder::include_peripherals](struct.ManagerBuilder.html#method.include_peripherals) method.
+    pub fn scope(&self) -> Scope {
+        self.0.scope()
+    }
+
+    /// Kind of the peripheral device powered by this battery.
+    ///
+    /// Kind is guessed from the device name, model and its parent device,
+    /// so it is not always accurate.\
+    /// Batteries with the `System` scope are returning `None`.
+    pub fn device_kind(&self) -> Option<DeviceKind> {
+        self.0.device_kind()
+    }
+
+    /// Battery charge control thresholds.
+    ///
+    /// Returns `None` if device does not support any of them.\
+    /// See [Manager::set_charge_thresholds](struct.Manager.html#method.set_charge_thresholds)
+    /// method to change them.
+    pub fn charge_thresholds(&self) -> Option<ChargeThresholds> {
+        self.0.charge_thresholds()
+    }
+
+    /// Current battery charging behaviour.
+    ///
+    /// Returns `None` if device does not support charging behaviour control.
+    pub fn charge_behaviour(&self) -> Option<ChargeBehaviour> {
+        self.0.charge_behaviour()
+    }
+
+    /// Charging behaviours supported by the device.
+    ///
+    /// See [Manager::set_charge_behaviour](struct.Manager.html#method.set_charge_behaviour)
+    /// method to change the current one.
+    pub fn available_charge_behaviours(&self) -> &[ChargeBehaviour] {
+        self.0.available_charge_behaviours()
+    }
+
+    /// Charging algorithm currently used by the charger.
+    ///
+    /// Currently supported on Linux only.
+    pub fn charge_type(&self) -> Option<ChargeType> {
+        self.0.charge_type()
+    }
+
+    /// Charging algorithms which can be selected for this battery.
+    ///
+    /// See [Manager::set_charge_type](struct.Manager.html#method.set_charge_type)
+    /// method to change it.
+    pub fn available_charge_types(&self) -> &[ChargeType] {
+        self.0.available_charge_types()
+    }
+
+    /// All the properties reported by the device driver, as is.
+    ///
+    /// Includes the properties which are not exposed by the other methods,
+    /// which makes it useful for troubleshooting and bug reports.\
+    /// Currently reported on Linux with the sysfs backend only,
+    /// where the keys are the `/sys/class/power_supply/{name}/` attribute names.
+    ///
+    /// Properties are read on the first call only, since it takes reading all the attribute files,
+    /// and are kept until the next [refresh](struct.Manager.html#method.refresh).
+    pub fn raw_properties(&self) -> &BTreeMap<String, String> {
+        self.0.raw_properties()
+    }
+
+    /// Reads the named property directly from the device.
+    ///
+    /// Unlike the other methods, value is not cached and is read on each call.
+    /// `None` is returned if the property is missing or device is unable to report it.\
+    /// Same as the [Battery::raw_properties](#method.raw_properties),
+    /// currently supported on Linux with the sysfs backend only.
+    ///
+    /// # Example
+    ///
+    /// ```edition2018
+    /// # use starship_battery::{Result, Manager};
+    /// # fn main() -> Result<()> {
+    /// for battery in Manager::new()?.batteries()? {
+    ///     println!("{:?}", battery?.read_property("charge_control_end_threshold")?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_property(&self, name: &str) -> Result<Option<String>> {
+        self.0.read_property(name)
+    }
+
     /// Remaining time till full battery.
     ///
     /// This is an instant value and may different vastly from call to call.
-    /// Any aggregation should be made by caller.
+    /// See [Estimator](struct.Estimator.html) for a smoothed one.
     ///
     /// If battery is not charging at the moment, this method will return `None`.
     pub fn time_to_full(&self) -> Option<Time> {
@@ -135,12 +437,36 @@ impl Battery {
     /// Remaining time till empty battery.
     ///
     /// This is an instant value and may different vastly from call to call.
-    /// Any aggregation should be made by caller.
+    /// See [Estimator](struct.Estimator.html) for a smoothed one.
     ///
     /// If battery is not discharging at the moment, this method will return `None`.
     pub fn time_to_empty(&self) -> Option<Time> {
         self.0.time_to_empty()
     }
+
+    /// Plain-data copy of the battery information, which can be cloned, sent across threads
+    /// and serialized with the `serde` feature.
+    ///
+    /// Raw device properties are not included,
+    /// see [Battery::snapshot_with_raw_properties](#method.snapshot_with_raw_properties).
+    ///
+    /// See [BatterySnapshot](struct.BatterySnapshot.html) for details.
+    pub fn snapshot(&self) -> BatterySnapshot {
+        BatterySnapshot::new(self, SystemTime::now(), false)
+    }
+
+    /// Same as the [Battery::snapshot](#method.snapshot),
+    /// but also includes the [raw device properties](#method.raw_properties).
+    pub fn snapshot_with_raw_properties(&self) -> BatterySnapshot {
+        BatterySnapshot::new(self, SystemTime::now(), true)
+    }
+}
+
+fn known<T>(provenance: Provenance, value: T) -> Option<T> {
+    match provenance {
+        Provenance::Defaulted => None,
+        _ => Some(value),
+    }
 }
 
 impl fmt::Debug for Battery {
@@ -151,19 +477,41 @@ impl fmt::Debug for Battery {
             .field("vendor", &self.vendor())
             .field("model", &self.model())
             .field("serial_number", &self.serial_number())
+            .field("manufacture_date", &self.manufacture_date())
             .field("technology", &self.technology())
+            .field("scope", &self.scope())
+            .field("device_kind", &self.device_kind())
             // common information
             .field("state", &self.state())
             .field("capacity", &self.state_of_health())
+            .field("health", &self.health())
             .field("temperature", &self.temperature())
             .field("percentage", &self.state_of_charge())
+            .field("capacity_level", &self.capacity_level())
+            .field("capacity_error_margin", &self.capacity_error_margin())
             .field("cycle_count", &self.cycle_count())
+            .field("charge_thresholds", &self.charge_thresholds())
+            .field("charge_behaviour", &self.charge_behaviour())
+            .field("charge_type", &self.charge_type())
             // energy stats
             .field("energy", &self.energy())
             .field("energy_full", &self.energy_full())
             .field("energy_full_design", &self.energy_full_design())
             .field("energy_rate", &self.energy_rate())
+            .field("power_flow", &self.power_flow())
             .field("voltage", &self.voltage())
+            // native charge stats
+            .field("current", &self.current())
+            .field("charge", &self.charge())
+            .field("charge_full", &self.charge_full())
+            .field("charge_full_design", &self.charge_full_design())
+            .field("charge_counter", &self.charge_counter())
+            // voltage stats
+            .field("voltage_min_design", &self.voltage_min_design())
+            .field("voltage_max_design", &self.voltage_max_design())
+            .field("voltage_ocv", &self.voltage_ocv())
+            .field("voltage_avg", &self.voltage_avg())
+            .field("design_voltage_source", &self.design_voltage_source())
             // charge stats
             .field("time_to_full", &self.time_to_full())
             .field("time_to_empty", &self.time_to_empty())
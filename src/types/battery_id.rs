@@ -0,0 +1,69 @@
+use std::fmt;
+
+/// Battery identifier, which is stable across the [Manager::batteries] calls.
+///
+/// It is made of the platform device name (`BAT0` on Linux, for example)
+/// and the battery serial number, if there is one,
+/// so the swapped battery with the same device name gets a different identifier.
+///
+/// Returned by the [Battery::id] method.
+///
+/// # Example
+///
+/// ```edition2018
+/// # use std::collections::HashMap;
+/// # use starship_battery::{Result, Manager};
+/// # fn main() -> Result<()> {
+/// let manager = Manager::new()?;
+/// let mut history = HashMap::new();
+/// for battery in manager.batteries()? {
+///     let battery = battery?;
+///     history.insert(battery.id(), vec![battery.state_of_charge()]);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Manager::batteries]: struct.Manager.html#method.batteries
+/// [Battery::id]: struct.Battery.html#method.id
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatteryId {
+    pub(crate) name: String,
+    pub(crate) serial_number: Option<String>,
+}
+
+impl BatteryId {
+    pub(crate) fn new(name: String, serial_number: Option<&str>) -> BatteryId {
+        let serial_number = serial_number
+            .map(str::trim)
+            .filter(|serial| !serial.is_empty())
+            .map(ToString::to_string);
+
+        BatteryId {
+            name,
+            serial_number,
+        }
+    }
+
+    /// Platform device name: the power supply name on Linux,
+    /// ACPI unit on FreeBSD, battery tag on Windows.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Battery serial number, if it is reported by the device.
+    pub fn serial_number(&self) -> Option<&str> {
+        self.serial_number.as_deref()
+    }
+}
+
+impl fmt::Display for BatteryId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.serial_number {
+            Some(serial_number) => write!(f, "{} ({})", self.name, serial_number),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
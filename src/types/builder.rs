@@ -0,0 +1,141 @@
+#[cfg(target_os = "linux")]
+use std::env;
+use std::fmt;
+#[cfg(target_os = "linux")]
+use std::path::{Path, PathBuf};
+
+use crate::platform::traits::*;
+use crate::platform::Manager as PlatformManager;
+#[cfg(all(target_os = "linux", feature = "upower"))]
+use crate::Backend;
+use crate::{Manager, Result, SanitizePolicy};
+
+#[cfg(target_os = "linux")]
+static SYSFS_ROOT_ENV: &str = "STARSHIP_BATTERY_SYSFS_ROOT";
+
+/// Builder for the [Manager] with a non-default configuration.
+///
+/// Created by the [Manager::builder](struct.Manager.html#method.builder) method.
+///
+/// # Example
+///
+/// ```edition2018
+/// # use starship_battery::{Result, Manager};
+/// # fn main() -> Result<()> {
+/// let manager = Manager::builder().build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Manager]: struct.Manager.html
+#[derive(Default)]
+pub struct ManagerBuilder {
+    #[cfg(target_os = "linux")]
+    sysfs_root: Option<PathBuf>,
+    #[cfg(all(target_os = "linux", feature = "upower"))]
+    backend: Backend,
+    include_peripherals: bool,
+    sanitize_policy: SanitizePolicy,
+}
+
+impl ManagerBuilder {
+    /// Use the `path` directory instead of the `/sys/class/power_supply`
+    /// as a source of the power supplies information.
+    ///
+    /// Might be useful for bind-mounted host sysfs inside of containers
+    /// or for a captured directory tree in tests.
+    #[cfg(target_os = "linux")]
+    pub fn sysfs_root<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.sysfs_root = Some(path.into());
+        self
+    }
+
+    /// Same as the [sysfs_root](#method.sysfs_root) method,
+    /// but takes the path from the `STARSHIP_BATTERY_SYSFS_ROOT` environment variable.
+    ///
+    /// Builder is not changed if the variable is not set or empty.
+    #[cfg(target_os = "linux")]
+    pub fn sysfs_root_from_env(self) -> Self {
+        match env::var_os(SYSFS_ROOT_ENV) {
+            Some(path) if !path.is_empty() => self.sysfs_root(path),
+            _ => self,
+        }
+    }
+
+    /// Select the source of the batteries information.
+    ///
+    /// By default sysfs is used if it is accessible, falling back to the UPower daemon otherwise.
+    /// Explicitly set [sysfs_root](#method.sysfs_root) always selects sysfs for the `Backend::Auto`.
+    ///
+    /// Available with the `upower` feature only.
+    #[cfg(all(target_os = "linux", feature = "upower"))]
+    pub fn backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Enumerate the peripheral batteries too, such as wireless mice, keyboards,
+    /// headsets and gamepads batteries.
+    ///
+    /// By default only batteries powering the whole system are enumerated.
+    /// See [Battery::scope](struct.Battery.html#method.scope)
+    /// and [Battery::device_kind](struct.Battery.html#method.device_kind) methods
+    /// to tell them apart.
+    ///
+    /// Currently peripheral batteries are enumerated on Linux only.
+    pub fn include_peripherals(mut self, value: bool) -> Self {
+        self.include_peripherals = value;
+        self
+    }
+
+    /// Thresholds for discarding the implausible energy rate and remaining time values,
+    /// see [SanitizePolicy](struct.SanitizePolicy.html) for the defaults.
+    ///
+    /// Values reported by the OS directly (ex. by the UPower daemon) are not affected.
+    /// Currently used on Linux only, other platforms are using the default policy.
+    pub fn sanitize_policy(mut self, policy: SanitizePolicy) -> Self {
+        self.sanitize_policy = policy;
+        self
+    }
+
+    /// Creates new manager value with the configuration from this builder.
+    pub fn build(self) -> Result<Manager> {
+        let inner = PlatformManager::with_builder(&self)?;
+
+        Ok(Manager::from(inner))
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(crate) fn get_sysfs_root(&self) -> Option<&Path> {
+        self.sysfs_root.as_deref()
+    }
+
+    #[cfg(all(target_os = "linux", feature = "upower"))]
+    pub(crate) fn get_backend(&self) -> Backend {
+        self.backend
+    }
+
+    #[allow(unused)]
+    pub(crate) fn get_include_peripherals(&self) -> bool {
+        self.include_peripherals
+    }
+
+    #[allow(unused)]
+    pub(crate) fn get_sanitize_policy(&self) -> SanitizePolicy {
+        self.sanitize_policy
+    }
+}
+
+impl fmt::Debug for ManagerBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("ManagerBuilder");
+        #[cfg(target_os = "linux")]
+        debug.field("sysfs_root", &self.sysfs_root);
+        #[cfg(all(target_os = "linux", feature = "upower"))]
+        debug.field("backend", &self.backend);
+        debug
+            .field("include_peripherals", &self.include_peripherals)
+            .field("sanitize_policy", &self.sanitize_policy)
+            .finish()
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt;
+use std::io;
+use std::str;
+
+use crate::units::ratio::percent;
+use crate::units::Ratio;
+
+/// Coarse battery capacity level.
+///
+/// Some devices are not reporting the precise state of charge value,
+/// but only the level it is currently in.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+#[derive(Default)]
+pub enum CapacityLevel {
+    #[default]
+    Unknown,
+    Critical,
+    Low,
+    Normal,
+    High,
+    Full,
+}
+
+impl CapacityLevel {
+    /// Representative state of charge value for this level.
+    ///
+    /// Values are the same as `upower` uses: 5 % for `Critical`, 10 % for `Low`,
+    /// 55 % for `Normal`, 70 % for `High` and 100 % for `Full` levels.\
+    /// `Unknown` level has no representative value.
+    pub fn state_of_charge(&self) -> Option<Ratio> {
+        match self {
+            CapacityLevel::Unknown => None,
+            CapacityLevel::Critical => Some(Ratio::new::<percent>(5.0)),
+            CapacityLevel::Low => Some(Ratio::new::<percent>(10.0)),
+            CapacityLevel::Normal => Some(Ratio::new::<percent>(55.0)),
+            CapacityLevel::High => Some(Ratio::new::<percent>(70.0)),
+            CapacityLevel::Full => Some(Ratio::new::<percent>(100.0)),
+        }
+    }
+}
+
+impl str::FromStr for CapacityLevel {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            _ if s.eq_ignore_ascii_case("Unknown") => Ok(CapacityLevel::Unknown),
+            _ if s.eq_ignore_ascii_case("Critical") => Ok(CapacityLevel::Critical),
+            _ if s.eq_ignore_ascii_case("Low") => Ok(CapacityLevel::Low),
+            _ if s.eq_ignore_ascii_case("Normal") => Ok(CapacityLevel::Normal),
+            _ if s.eq_ignore_ascii_case("High") => Ok(CapacityLevel::High),
+            _ if s.eq_ignore_ascii_case("Full") => Ok(CapacityLevel::Full),
+            _ => Err(io::Error::from(io::ErrorKind::InvalidData)),
+        }
+    }
+}
+
+impl fmt::Display for CapacityLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            CapacityLevel::Unknown => "unknown",
+            CapacityLevel::Critical => "critical",
+            CapacityLevel::Low => "low",
+            CapacityLevel::Normal => "normal",
+            CapacityLevel::High => "high",
+            CapacityLevel::Full => "full",
+        };
+
+        write!(f, "{}", display)
+    }
+}
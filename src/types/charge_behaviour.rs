@@ -0,0 +1,57 @@
+use std::fmt;
+use std::io;
+use std::str;
+
+/// Possible battery charging behaviours.
+///
+/// See [Manager::set_charge_behaviour](struct.Manager.html#method.set_charge_behaviour)
+/// method to change it.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+#[derive(Default)]
+pub enum ChargeBehaviour {
+    /// Charge normally, respecting the charge thresholds.
+    #[default]
+    Auto,
+    /// Do not charge while AC is attached.
+    InhibitCharge,
+    /// Do not charge while AC is attached and the system is awake.
+    InhibitChargeAwake,
+    /// Force discharge while AC is attached.
+    ForceDischarge,
+}
+
+impl str::FromStr for ChargeBehaviour {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            _ if s.eq_ignore_ascii_case("auto") => Ok(ChargeBehaviour::Auto),
+            _ if s.eq_ignore_ascii_case("inhibit-charge") => Ok(ChargeBehaviour::InhibitCharge),
+            _ if s.eq_ignore_ascii_case("inhibit-charge-awake") => {
+                Ok(ChargeBehaviour::InhibitChargeAwake)
+            }
+            _ if s.eq_ignore_ascii_case("force-discharge") => Ok(ChargeBehaviour::ForceDischarge),
+            _ => Err(io::Error::from(io::ErrorKind::InvalidData)),
+        }
+    }
+}
+
+impl fmt::Display for ChargeBehaviour {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            ChargeBehaviour::Auto => "auto",
+            ChargeBehaviour::InhibitCharge => "inhibit-charge",
+            ChargeBehaviour::InhibitChargeAwake => "inhibit-charge-awake",
+            ChargeBehaviour::ForceDischarge => "force-discharge",
+        };
+
+        write!(f, "{}", display)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::units::Ratio;
+
+/// Battery charge control thresholds.
+///
+/// Charging starts when the battery state of charge drops below the `start` threshold
+/// and stops when it reaches the `end` threshold.
+/// Battery is in the [State::Paused](enum.State.html#variant.Paused) state in between.
+///
+/// Devices may support either one or both of these thresholds.
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct ChargeThresholds {
+    pub(crate) start: Option<Ratio>,
+    pub(crate) end: Option<Ratio>,
+}
+
+impl ChargeThresholds {
+    /// State of charge below which the battery starts charging.
+    pub fn start(&self) -> Option<Ratio> {
+        self.start
+    }
+
+    /// State of charge at which the battery stops charging.
+    pub fn end(&self) -> Option<Ratio> {
+        self.end
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt;
+use std::io;
+use std::str;
+
+/// Possible battery charging algorithms.
+///
+/// See [Manager::set_charge_type](struct.Manager.html#method.set_charge_type)
+/// method to change it.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+#[derive(Default)]
+pub enum ChargeType {
+    #[default]
+    Unknown,
+    /// Charging is not applicable for the device.
+    NotAvailable,
+    /// Slow charging with a small current.
+    Trickle,
+    /// Quick charging with a high current.
+    Fast,
+    /// Charging with the default speed.
+    Standard,
+    /// Charging speed and thresholds are adjusted based on the usage patterns.
+    Adaptive,
+    /// Charging with the user defined thresholds.
+    Custom,
+    /// Slower charging, prolonging the battery life.
+    LongLife,
+    /// Battery is not charged, power is passed directly to the system.
+    Bypass,
+}
+
+impl str::FromStr for ChargeType {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            _ if s.eq_ignore_ascii_case("Unknown") => Ok(ChargeType::Unknown),
+            _ if s.eq_ignore_ascii_case("N/A") => Ok(ChargeType::NotAvailable),
+            _ if s.eq_ignore_ascii_case("Trickle") => Ok(ChargeType::Trickle),
+            _ if s.eq_ignore_ascii_case("Fast") => Ok(ChargeType::Fast),
+            _ if s.eq_ignore_ascii_case("Standard") => Ok(ChargeType::Standard),
+            _ if s.eq_ignore_ascii_case("Adaptive") => Ok(ChargeType::Adaptive),
+            _ if s.eq_ignore_ascii_case("Custom") => Ok(ChargeType::Custom),
+            _ if s.eq_ignore_ascii_case("Long Life") => Ok(ChargeType::LongLife),
+            _ if s.eq_ignore_ascii_case("long-life") => Ok(ChargeType::LongLife),
+            _ if s.eq_ignore_ascii_case("Bypass") => Ok(ChargeType::Bypass),
+            _ => Err(io::Error::from(io::ErrorKind::InvalidData)),
+        }
+    }
+}
+
+impl fmt::Display for ChargeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            ChargeType::Unknown => "unknown",
+            ChargeType::NotAvailable => "not-available",
+            ChargeType::Trickle => "trickle",
+            ChargeType::Fast => "fast",
+            ChargeType::Standard => "standard",
+            ChargeType::Adaptive => "adaptive",
+            ChargeType::Custom => "custom",
+            ChargeType::LongLife => "long-life",
+            ChargeType::Bypass => "bypass",
+        };
+
+        write!(f, "{}", display)
+    }
+}
@@ -0,0 +1,244 @@
+use num_traits::identities::Zero;
+
+use crate::platform::traits::BatteryDevice;
+use crate::units::ratio::percent;
+use crate::units::{
+    Bound, ElectricPotential, Energy, Power, Ratio, ThermodynamicTemperature, Time,
+};
+use crate::{Battery, PowerFlow, SanitizePolicy, Scope, State, Technology};
+
+/// Aggregated information of all the batteries powering the system,
+/// same as the UPower "display device".
+///
+/// Created by the [Manager::composite](struct.Manager.html#method.composite) method.
+///
+/// Energy values are summed up, state of charge is weighted by the batteries capacities,
+/// and time estimations are made for the whole system, as if it had one big battery.
+#[derive(Debug, Clone)]
+pub struct CompositeBattery {
+    batteries: usize,
+    state: State,
+    state_of_charge: Ratio,
+    energy: Energy,
+    energy_full: Energy,
+    energy_full_design: Energy,
+    power_flow: PowerFlow,
+    policy: SanitizePolicy,
+}
+
+impl CompositeBattery {
+    /// Returns `None` if there are no batteries with the system scope.
+    pub(crate) fn new<'a, T>(batteries: T) -> Option<CompositeBattery>
+    where
+        T: IntoIterator<Item = &'a Battery>,
+    {
+        let batteries = batteries
+            .into_iter()
+            .filter(|battery| battery.scope() == Scope::System)
+            .collect::<Vec<_>>();
+        if batteries.is_empty() {
+            return None;
+        }
+
+        let energy: Energy = batteries.iter().map(|battery| battery.energy()).sum();
+        let energy_full: Energy = batteries.iter().map(|battery| battery.energy_full()).sum();
+        let energy_full_design: Energy = batteries
+            .iter()
+            .map(|battery| battery.energy_full_design())
+            .sum();
+        let power: Power = batteries
+            .iter()
+            .map(|battery| battery.power_flow().power())
+            .sum();
+
+        // Batteries reporting the capacity level only have no energy values,
+        // falling back to the average state of charge for them
+        let state_of_charge = if energy_full > Energy::zero() {
+            (energy / energy_full).into_bounded()
+        } else {
+            let total: Ratio = batteries
+                .iter()
+                .map(|battery| battery.state_of_charge())
+                .sum();
+            total / batteries.len() as f32
+        };
+
+        let power_flow = match power {
+            _ if power > Power::zero() => PowerFlow::Charging(power),
+            _ if power < Power::zero() => PowerFlow::Discharging(-power),
+            _ => PowerFlow::Idle,
+        };
+        // State follows the net flow, so the time estimations are made in the same direction,
+        // even if one battery is charging while the other one is discharging
+        let state = match power_flow {
+            PowerFlow::Charging(_) => State::Charging,
+            PowerFlow::Discharging(_) => State::Discharging,
+            PowerFlow::Idle => combined_state(batteries.iter().map(|battery| battery.state())),
+        };
+
+        Some(CompositeBattery {
+            batteries: batteries.len(),
+            state,
+            state_of_charge,
+            energy,
+            energy_full,
+            energy_full_design,
+            power_flow,
+            // All batteries are coming from the same manager
+            policy: batteries[0].sanitize_policy(),
+        })
+    }
+
+    /// Number of the aggregated batteries.
+    pub fn batteries(&self) -> usize {
+        self.batteries
+    }
+
+    /// Combined state of charge, weighted by the batteries capacities.
+    pub fn state_of_charge(&self) -> Ratio {
+        BatteryDevice::state_of_charge(self)
+    }
+
+    /// Combined state of health.
+    pub fn state_of_health(&self) -> Ratio {
+        BatteryDevice::state_of_health(self)
+    }
+
+    /// Combined battery state.
+    ///
+    /// State follows the direction of the [net power flow](#method.power_flow),
+    /// for example, the system is considered discharging if one battery is charging
+    /// slower than the other one is discharging.
+    /// If there is no net flow, the whole system is considered discharging
+    /// if any of the batteries is discharging, otherwise it is charging if any of them is charging.
+    /// Batteries with the same state are sharing it with the composite,
+    /// and the `State::Unknown` is reported for the mixed ones.
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// Sum of the batteries energy.
+    pub fn energy(&self) -> Energy {
+        self.energy
+    }
+
+    /// Sum of the batteries energy when they are considered full.
+    pub fn energy_full(&self) -> Energy {
+        self.energy_full
+    }
+
+    /// Sum of the batteries designed energy.
+    pub fn energy_full_design(&self) -> Energy {
+        self.energy_full_design
+    }
+
+    /// Net amount of energy flowing in or out of the batteries.
+    ///
+    /// See [CompositeBattery::power_flow](#method.power_flow) for its direction.
+    pub fn energy_rate(&self) -> Power {
+        BatteryDevice::energy_rate(self)
+    }
+
+    /// Net power flow of the batteries.
+    pub fn power_flow(&self) -> PowerFlow {
+        self.power_flow
+    }
+
+    /// Remaining time till the full charge of all batteries.
+    pub fn time_to_full(&self) -> Option<Time> {
+        BatteryDevice::time_to_full(self)
+    }
+
+    /// Remaining time till the full discharge of all batteries.
+    pub fn time_to_empty(&self) -> Option<Time> {
+        BatteryDevice::time_to_empty(self)
+    }
+}
+
+// Allows to reuse the default calculations for the composite values.
+// Values which are not making sense for the composite are not exposed.
+impl BatteryDevice for CompositeBattery {
+    fn state_of_charge(&self) -> Ratio {
+        self.state_of_charge
+    }
+
+    fn state_of_health(&self) -> Ratio {
+        if self.energy_full_design.is_zero() {
+            Ratio::new::<percent>(100.0)
+        } else {
+            (self.energy_full / self.energy_full_design).into_bounded()
+        }
+    }
+
+    fn energy(&self) -> Energy {
+        self.energy
+    }
+
+    fn energy_full(&self) -> Energy {
+        self.energy_full
+    }
+
+    fn energy_full_design(&self) -> Energy {
+        self.energy_full_design
+    }
+
+    fn energy_rate(&self) -> Power {
+        self.power_flow.power().abs()
+    }
+
+    fn state(&self) -> State {
+        self.state
+    }
+
+    fn power_flow(&self) -> PowerFlow {
+        self.power_flow
+    }
+
+    fn sanitize_policy(&self) -> SanitizePolicy {
+        self.policy
+    }
+
+    fn voltage(&self) -> ElectricPotential {
+        ElectricPotential::zero()
+    }
+
+    fn temperature(&self) -> Option<ThermodynamicTemperature> {
+        None
+    }
+
+    fn vendor(&self) -> Option<&str> {
+        None
+    }
+
+    fn model(&self) -> Option<&str> {
+        None
+    }
+
+    fn serial_number(&self) -> Option<&str> {
+        None
+    }
+
+    fn name(&self) -> String {
+        String::new()
+    }
+
+    fn technology(&self) -> Technology {
+        Technology::Unknown
+    }
+
+    fn cycle_count(&self) -> Option<u32> {
+        None
+    }
+}
+
+fn combined_state<T: Iterator<Item = State>>(states: T) -> State {
+    let states = states.collect::<Vec<_>>();
+    match () {
+        _ if states.contains(&State::Discharging) => State::Discharging,
+        _ if states.contains(&State::Charging) => State::Charging,
+        _ if states.windows(2).all(|pair| pair[0] == pair[1]) => {
+            states.first().copied().unwrap_or_default()
+        }
+        _ => State::Unknown,
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt;
+
+/// Voltage value used to convert the charge values reported by the device into the energy ones.
+///
+/// Devices reporting charge in µAh instead of energy in µWh are having their
+/// [Battery::energy](struct.Battery.html#method.energy) and related values
+/// multiplied by this voltage, see
+/// [Battery::design_voltage_source](struct.Battery.html#method.design_voltage_source).
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+pub enum DesignVoltageSource {
+    /// Maximum design voltage of the battery.
+    MaxDesign,
+    /// Minimum design voltage of the battery.
+    MinDesign,
+    /// Design voltage of the battery, which is not specified as a minimum or maximum one.
+    Design,
+    /// Voltage reported by the driver as `voltage_present`.
+    Present,
+    /// Instantaneous voltage of the battery, used when no design voltage is available.
+    Now,
+}
+
+impl fmt::Display for DesignVoltageSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            DesignVoltageSource::MaxDesign => "max-design",
+            DesignVoltageSource::MinDesign => "min-design",
+            DesignVoltageSource::Design => "design",
+            DesignVoltageSource::Present => "present",
+            DesignVoltageSource::Now => "now",
+        };
+
+        write!(f, "{}", display)
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt;
+
+/// Kind of the peripheral device powered by the battery.
+///
+/// See [Battery::device_kind](struct.Battery.html#method.device_kind) for details.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+#[derive(Default)]
+pub enum DeviceKind {
+    #[default]
+    Unknown,
+    Mouse,
+    Keyboard,
+    Headset,
+    Gamepad,
+    Phone,
+}
+
+impl fmt::Display for DeviceKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            DeviceKind::Unknown => "unknown",
+            DeviceKind::Mouse => "mouse",
+            DeviceKind::Keyboard => "keyboard",
+            DeviceKind::Headset => "headset",
+            DeviceKind::Gamepad => "gamepad",
+            DeviceKind::Phone => "phone",
+        };
+
+        write!(f, "{}", display)
+    }
+}
@@ -0,0 +1,214 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use num_traits::identities::Zero;
+
+use crate::platform::traits::{time_to_empty, time_to_full, BatteryDevice};
+use crate::units::{Power, Time};
+use crate::{Battery, Manager, Result, State};
+
+/// Number of consecutive outliers after which they are considered a new normal,
+/// for example, when heavy workload starts.
+const MAX_OUTLIERS: usize = 3;
+
+/// Stateful estimator of the battery energy rate and remaining time.
+///
+/// [Battery::energy_rate](struct.Battery.html#method.energy_rate) and the time values
+/// calculated from it are instant values, which may differ vastly from refresh to refresh.
+/// Estimator wraps the battery and applies exponential smoothing to the energy rate
+/// on each [Estimator::refresh](#method.refresh) call.
+///
+/// Smoothed values are reset when battery state changes, for example,
+/// when the charger is plugged in.
+///
+/// # Example
+///
+/// ```edition2018,no_run
+/// # use std::time::Duration;
+/// # use starship_battery::{Estimator, Result, Manager};
+/// # fn main() -> Result<()> {
+/// let manager = Manager::new()?;
+/// if let Some(battery) = manager.batteries()?.next() {
+///     let mut estimator = Estimator::new(battery?).window(Duration::from_secs(120));
+///     loop {
+///         std::thread::sleep(Duration::from_secs(5));
+///         estimator.refresh(&manager)?;
+///         println!("{:?}", estimator.time_to_empty());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Estimator {
+    battery: Battery,
+    window: Duration,
+    outlier_ratio: f32,
+
+    state: State,
+    rate: Option<Power>,
+    timestamp: Instant,
+    outliers: usize,
+}
+
+impl Estimator {
+    /// Creates new estimator for the `battery`, with one minute smoothing window
+    /// and samples differing more than three times from the smoothed rate rejected as outliers.
+    pub fn new(battery: Battery) -> Estimator {
+        let mut estimator = Estimator {
+            state: battery.state(),
+            battery,
+            window: Duration::from_secs(60),
+            outlier_ratio: 3.0,
+            rate: None,
+            timestamp: Instant::now(),
+            outliers: 0,
+        };
+        estimator.update(estimator.timestamp);
+
+        estimator
+    }
+
+    /// Time constant of the exponential smoothing.
+    ///
+    /// Samples older than the window are having roughly one third of the weight of the fresh ones,
+    /// so the longer window makes estimations more stable, but slower to react.
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Energy rate samples which are `ratio` times greater or lower than the smoothed rate
+    /// are rejected as outliers, unless there are a few of them in a row.
+    ///
+    /// Valid range is `(1.0, f32::INFINITY]`, where `f32::INFINITY` disables outliers rejection.
+    /// Default is `3.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is not greater than `1.0` (including `NaN`),
+    /// since every sample would be rejected and the estimation would never change.
+    pub fn outlier_ratio(mut self, ratio: f32) -> Self {
+        assert!(ratio > 1.0, "Outlier ratio should be greater than 1.0");
+        self.outlier_ratio = ratio;
+        self
+    }
+
+    /// Refreshes the wrapped battery with the `manager` and updates the estimations.
+    pub fn refresh(&mut self, manager: &Manager) -> Result<()> {
+        self.refresh_at(manager, Instant::now())
+    }
+
+    pub(crate) fn refresh_at(&mut self, manager: &Manager, timestamp: Instant) -> Result<()> {
+        manager.refresh(&mut self.battery)?;
+        self.update(timestamp);
+
+        Ok(())
+    }
+
+    /// Drops the smoothed values, estimation starts over with the next refresh.
+    pub fn reset(&mut self) {
+        self.rate = None;
+        self.outliers = 0;
+    }
+
+    /// Wrapped battery, as of the last refresh.
+    pub fn battery(&self) -> &Battery {
+        &self.battery
+    }
+
+    /// Unwraps the battery.
+    pub fn into_inner(self) -> Battery {
+        self.battery
+    }
+
+    /// Smoothed energy rate, `None` if there are no suitable samples yet.
+    pub fn energy_rate(&self) -> Option<Power> {
+        self.rate
+    }
+
+    /// Remaining time till full battery, calculated with the smoothed energy rate.
+    ///
+    /// Same as for the [Battery::time_to_full](struct.Battery.html#method.time_to_full),
+    /// it is `None` if battery is not charging at the moment.
+    pub fn time_to_full(&self) -> Option<Time> {
+        time_to_full(
+            self.battery.state(),
+            self.battery.energy(),
+            self.battery.energy_full(),
+            self.rate?,
+            &self.battery.sanitize_policy(),
+        )
+    }
+
+    /// Remaining time till empty battery, calculated with the smoothed energy rate.
+    ///
+    /// Same as for the [Battery::time_to_empty](struct.Battery.html#method.time_to_empty),
+    /// it is `None` if battery is not discharging at the moment.
+    pub fn time_to_empty(&self) -> Option<Time> {
+        time_to_empty(
+            self.battery.state(),
+            self.battery.energy(),
+            self.rate?,
+            &self.battery.sanitize_policy(),
+        )
+    }
+
+    fn update(&mut self, timestamp: Instant) {
+        let state = self.battery.state();
+        if state != self.state {
+            self.state = state;
+            self.reset();
+        }
+
+        let elapsed = timestamp.saturating_duration_since(self.timestamp);
+        self.timestamp = timestamp;
+
+        let sample = self.battery.energy_rate();
+        // Zero rate while charging or discharging means that device failed to report it,
+        // rather than there is no energy flow at all
+        if sample.is_zero() || !matches!(state, State::Charging | State::Discharging) {
+            return;
+        }
+
+        let rate = match self.rate {
+            Some(rate) => rate,
+            None => {
+                self.rate = Some(sample);
+                return;
+            }
+        };
+
+        let ratio = (sample / rate).value;
+        if ratio > self.outlier_ratio || ratio < 1.0 / self.outlier_ratio {
+            self.outliers += 1;
+            if self.outliers < MAX_OUTLIERS {
+                return;
+            }
+            // Not an outlier anymore, but a sudden change of the load
+            self.outliers = 0;
+            self.rate = Some(sample);
+            return;
+        }
+        self.outliers = 0;
+
+        let alpha = if self.window.is_zero() {
+            1.0
+        } else {
+            1.0 - (-elapsed.as_secs_f32() / self.window.as_secs_f32()).exp()
+        };
+        self.rate = Some(rate + (sample - rate) * alpha);
+    }
+}
+
+impl fmt::Debug for Estimator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Estimator")
+            .field("battery", &self.battery)
+            .field("window", &self.window)
+            .field("outlier_ratio", &self.outlier_ratio)
+            .field("energy_rate", &self.rate)
+            .field("time_to_full", &self.time_to_full())
+            .field("time_to_empty", &self.time_to_empty())
+            .finish()
+    }
+}
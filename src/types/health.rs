@@ -0,0 +1,83 @@
+use std::fmt;
+use std::io;
+use std::str;
+
+/// Possible battery health status values, as reported by the device firmware.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+#[derive(Default)]
+pub enum Health {
+    #[default]
+    Unknown,
+    Good,
+    Overheat,
+    Dead,
+    OverVoltage,
+    UnspecifiedFailure,
+    Cold,
+    WatchdogTimerExpire,
+    SafetyTimerExpire,
+    OverCurrent,
+    CalibrationRequired,
+    Warm,
+    Cool,
+    Hot,
+    NoBattery,
+}
+
+impl str::FromStr for Health {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Ref: `POWER_SUPPLY_HEALTH_*` strings at
+        // https://github.com/torvalds/linux/blob/master/drivers/power/supply/power_supply_sysfs.c
+        match s {
+            _ if s.eq_ignore_ascii_case("Unknown") => Ok(Health::Unknown),
+            _ if s.eq_ignore_ascii_case("Good") => Ok(Health::Good),
+            _ if s.eq_ignore_ascii_case("Overheat") => Ok(Health::Overheat),
+            _ if s.eq_ignore_ascii_case("Dead") => Ok(Health::Dead),
+            _ if s.eq_ignore_ascii_case("Over voltage") => Ok(Health::OverVoltage),
+            _ if s.eq_ignore_ascii_case("Unspecified failure") => Ok(Health::UnspecifiedFailure),
+            _ if s.eq_ignore_ascii_case("Cold") => Ok(Health::Cold),
+            _ if s.eq_ignore_ascii_case("Watchdog timer expire") => Ok(Health::WatchdogTimerExpire),
+            _ if s.eq_ignore_ascii_case("Safety timer expire") => Ok(Health::SafetyTimerExpire),
+            _ if s.eq_ignore_ascii_case("Over current") => Ok(Health::OverCurrent),
+            _ if s.eq_ignore_ascii_case("Calibration required") => Ok(Health::CalibrationRequired),
+            _ if s.eq_ignore_ascii_case("Warm") => Ok(Health::Warm),
+            _ if s.eq_ignore_ascii_case("Cool") => Ok(Health::Cool),
+            _ if s.eq_ignore_ascii_case("Hot") => Ok(Health::Hot),
+            _ if s.eq_ignore_ascii_case("No battery") => Ok(Health::NoBattery),
+            _ => Err(io::Error::from(io::ErrorKind::InvalidData)),
+        }
+    }
+}
+
+impl fmt::Display for Health {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            Health::Unknown => "unknown",
+            Health::Good => "good",
+            Health::Overheat => "overheat",
+            Health::Dead => "dead",
+            Health::OverVoltage => "over-voltage",
+            Health::UnspecifiedFailure => "unspecified-failure",
+            Health::Cold => "cold",
+            Health::WatchdogTimerExpire => "watchdog-timer-expire",
+            Health::SafetyTimerExpire => "safety-timer-expire",
+            Health::OverCurrent => "over-current",
+            Health::CalibrationRequired => "calibration-required",
+            Health::Warm => "warm",
+            Health::Cool => "cool",
+            Health::Hot => "hot",
+            Health::NoBattery => "no-battery",
+        };
+
+        write!(f, "{}", display)
+    }
+}
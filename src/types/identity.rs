@@ -0,0 +1,44 @@
+use crate::ManufactureDate;
+
+/// Battery identification attributes.
+///
+/// Returned by the [Battery::identity](struct.Battery.html#method.identity) method,
+/// all of them are not changing during the battery lifetime.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Identity {
+    pub(crate) vendor: Option<String>,
+    pub(crate) model: Option<String>,
+    pub(crate) serial_number: Option<String>,
+    pub(crate) manufacture_date: Option<ManufactureDate>,
+    pub(crate) battery_type: Option<String>,
+}
+
+impl Identity {
+    /// Same as [Battery::vendor](struct.Battery.html#method.vendor).
+    pub fn vendor(&self) -> Option<&str> {
+        self.vendor.as_deref()
+    }
+
+    /// Same as [Battery::model](struct.Battery.html#method.model).
+    pub fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+
+    /// Same as [Battery::serial_number](struct.Battery.html#method.serial_number).
+    pub fn serial_number(&self) -> Option<&str> {
+        self.serial_number.as_deref()
+    }
+
+    /// Same as [Battery::manufacture_date](struct.Battery.html#method.manufacture_date).
+    pub fn manufacture_date(&self) -> Option<ManufactureDate> {
+        self.manufacture_date
+    }
+
+    /// Battery type (chemistry) as it is reported by the device, for example, `LION` or `Li-poly`.
+    ///
+    /// Might be useful if [Battery::technology](struct.Battery.html#method.technology)
+    /// returns `Technology::Unknown` for it.
+    pub fn battery_type(&self) -> Option<&str> {
+        self.battery_type.as_deref()
+    }
+}
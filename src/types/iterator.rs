@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::platform::Iterator as PlatformIterator;
-use crate::{Battery, Result};
+use crate::{Adapter, Battery, Result};
 
 /// An iterator that yields [batteries] available in system.
 ///
@@ -39,3 +39,36 @@ impl fmt::Debug for Batteries {
         f.debug_struct("Batteries").field("impl", &self.0).finish()
     }
 }
+
+/// An iterator that yields [adapters] available in system.
+///
+/// This struct is created by the [Manager::adapters](struct.Manager.html#method.adapters) method.
+/// See its documentation for more.
+///
+/// [adapters]: struct.Adapter.html
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Adapters(Box<dyn Iterator<Item = Result<Adapter>> + Send>);
+
+impl Iterator for Adapters {
+    type Item = Result<Adapter>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl From<Box<dyn Iterator<Item = Result<Adapter>> + Send>> for Adapters {
+    fn from(inner: Box<dyn Iterator<Item = Result<Adapter>> + Send>) -> Adapters {
+        Adapters(inner)
+    }
+}
+
+impl fmt::Debug for Adapters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Adapters").finish()
+    }
+}
@@ -1,10 +1,19 @@
 use std::fmt;
+use std::io;
+#[cfg(target_os = "linux")]
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::platform::traits::*;
 use crate::platform::Iterator as PlatformIterator;
 use crate::platform::Manager as PlatformManager;
-use crate::{Batteries, Battery, Result};
+use crate::units::Ratio;
+#[cfg(target_os = "linux")]
+use crate::Monitor;
+use crate::{
+    Adapters, Batteries, Battery, BatteryId, ChargeBehaviour, ChargeType, CompositeBattery, Error,
+    ManagerBuilder, RefreshSummary, Result,
+};
 
 /// Manager for batteries available in system.
 ///
@@ -32,9 +41,23 @@ impl Manager {
     pub fn new() -> Result<Manager> {
         let inner = PlatformManager::new()?;
 
-        Ok(Manager {
-            inner: Arc::new(inner),
-        })
+        Ok(Manager::from(inner))
+    }
+
+    /// Creates new builder for the manager with a non-default configuration.
+    ///
+    /// See [ManagerBuilder](struct.ManagerBuilder.html) for available options.
+    pub fn builder() -> ManagerBuilder {
+        ManagerBuilder::default()
+    }
+
+    /// Creates new manager value, which is using the `root` directory
+    /// instead of the `/sys/class/power_supply`.
+    ///
+    /// Shortcut for the `Manager::builder().sysfs_root(root).build()`.
+    #[cfg(target_os = "linux")]
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Result<Manager> {
+        Manager::builder().sysfs_root(root).build()
     }
 
     /// Returns an iterator over available batteries.
@@ -50,10 +73,303 @@ impl Manager {
         Ok(Batteries::from(inner))
     }
 
+    /// Returns the battery with the `id` identifier,
+    /// or `None` if it is not available anymore.
+    ///
+    /// On Linux the battery is read directly by its device name,
+    /// other platforms are looking it up among the enumerated batteries.
+    ///
+    /// # Example
+    ///
+    /// ```edition2018
+    /// # use starship_battery::{Result, Manager};
+    /// # fn main() -> Result<()> {
+    /// let manager = Manager::new()?;
+    /// if let Some(battery) = manager.batteries()?.next() {
+    ///     let id = battery?.id();
+    ///     println!("{}: {:?}", id, manager.battery(&id)?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn battery(&self, id: &BatteryId) -> Result<Option<Battery>> {
+        match self.inner.battery(id.name()) {
+            // Same device name might be used by the swapped battery
+            Ok(device) => {
+                return Ok(device
+                    .map(Battery::from)
+                    .filter(|battery| battery.id() == *id))
+            }
+            Err(e) if e.kind() == io::ErrorKind::Unsupported => {}
+            Err(e) => return Err(e),
+        }
+
+        // Errors of the other batteries are not relevant for the lookup
+        let battery = self
+            .batteries()?
+            .filter_map(|battery| battery.ok())
+            .find(|battery| battery.id() == *id);
+
+        Ok(battery)
+    }
+
+    /// Returns aggregated information of all batteries powering the system,
+    /// such as the internal and the swappable batteries of dual-battery laptops.
+    ///
+    /// Peripheral batteries are not aggregated, even if they are enumerated,
+    /// see [ManagerBuilder::include_peripherals](struct.ManagerBuilder.html#method.include_peripherals).
+    /// Batteries which are failing to read are skipped as well.
+    /// `None` is returned if there are no system batteries.
+    ///
+    /// # Example
+    ///
+    /// ```edition2018
+    /// # use starship_battery::{Result, Manager};
+    /// # fn main() -> Result<()> {
+    /// if let Some(composite) = Manager::new()?.composite()? {
+    ///     println!("{:?}, {:?}", composite.state(), composite.state_of_charge());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn composite(&self) -> Result<Option<CompositeBattery>> {
+        // Broken battery should not hide the rest of them
+        let batteries = self
+            .batteries()?
+            .filter_map(|battery| battery.ok())
+            .collect::<Vec<_>>();
+
+        Ok(CompositeBattery::new(&batteries))
+    }
+
+    /// Returns an iterator over available power supplies, which are not batteries,
+    /// such as AC adapters, USB ports and UPS.
+    ///
+    /// Currently adapters are enumerated on Linux only,
+    /// other platforms are returning an empty iterator.
+    pub fn adapters(&self) -> Result<Adapters> {
+        let inner = self.inner.adapters()?;
+
+        Ok(Adapters::from(inner))
+    }
+
+    /// Returns an iterator over battery events: batteries being added, removed
+    /// or changed, as reported by the kernel.
+    ///
+    /// Batteries are selected the same way as in the [Manager::batteries](#method.batteries) method,
+    /// including the [ManagerBuilder::include_peripherals](struct.ManagerBuilder.html#method.include_peripherals)
+    /// and [ManagerBuilder::sysfs_root](struct.ManagerBuilder.html#method.sysfs_root) options.
+    /// Manager with the UPower backend returns the `ErrorKind::Unsupported` error.
+    ///
+    /// # Example
+    ///
+    /// ```edition2018,no_run
+    /// # use starship_battery::{BatteryEvent, Result, Manager};
+    /// # fn main() -> Result<()> {
+    /// for event in Manager::new()?.monitor()? {
+    ///     match event? {
+    ///         BatteryEvent::Added(battery) => println!("Added: {:?}", battery),
+    ///         BatteryEvent::Removed(name) => println!("Removed: {}", name),
+    ///         BatteryEvent::Changed(battery) => println!("Changed: {:?}", battery),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(target_os = "linux")]
+    pub fn monitor(&self) -> Result<Monitor> {
+        Monitor::new(self)
+    }
+
     /// Refresh battery information in-place.
     pub fn refresh(&self, battery: &mut Battery) -> Result<()> {
         self.inner.refresh(battery)
     }
+
+    /// Refresh all the `batteries` in-place and sync the list with the available batteries.
+    ///
+    /// Batteries which are not available anymore are removed from the list
+    /// and the new ones are appended to its end, the rest of them are refreshed
+    /// same as with the [Manager::refresh](#method.refresh) method.
+    /// Batteries are matched by their [identifiers](struct.Battery.html#method.id).
+    ///
+    /// Batteries which are failing to refresh because their device is not available anymore
+    /// are considered removed, other errors (including the `ErrorKind::NotFound` errors
+    /// for the missing values of the available batteries) are returned as is,
+    /// with no batteries removed from the list.
+    /// New batteries which are failing to read are skipped until the next refresh.
+    ///
+    /// # Example
+    ///
+    /// ```edition2018
+    /// # use starship_battery::{Result, Manager};
+    /// # fn main() -> Result<()> {
+    /// let manager = Manager::new()?;
+    /// let mut batteries = Vec::new();
+    /// let summary = manager.refresh_all(&mut batteries)?;
+    /// for id in summary.added() {
+    ///     println!("Added: {}", id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn refresh_all(&self, batteries: &mut Vec<Battery>) -> Result<RefreshSummary> {
+        let mut summary = RefreshSummary::default();
+
+        // Refreshing the existing batteries in-place keeps the state accumulated
+        // between refreshes, such as the sampled energy rate.
+        // List is not changed until all of them are refreshed, so it stays intact on error
+        let mut is_removed = Vec::with_capacity(batteries.len());
+        for battery in batteries.iter_mut() {
+            match self.refresh(battery) {
+                Ok(()) => is_removed.push(false),
+                // Battery was unplugged, other `NotFound` errors are caused
+                // by the missing attributes of the still available battery
+                Err(e) if e.is_device_removed() => is_removed.push(true),
+                Err(e) => return Err(e),
+            }
+        }
+        let mut is_removed = is_removed.into_iter();
+        batteries.retain(|battery| match is_removed.next() {
+            Some(true) => {
+                summary.removed.push(battery.id());
+                false
+            }
+            _ => {
+                summary.refreshed.push(battery.id());
+                true
+            }
+        });
+
+        let added = match self.inner.battery_names() {
+            Ok(names) => {
+                let mut names = names
+                    .into_iter()
+                    .filter(|name| !batteries.iter().any(|battery| battery.id().name() == name))
+                    .collect::<Vec<_>>();
+                names.sort();
+
+                // Unreadable or just removed batteries are skipped,
+                // they will be picked up with the next refresh, if possible
+                names
+                    .iter()
+                    .filter_map(|name| self.inner.battery(name).ok().flatten())
+                    .map(Battery::from)
+                    .collect::<Vec<_>>()
+            }
+            // Platforms which are unable to list the names are building all the devices
+            Err(e) if e.kind() == io::ErrorKind::Unsupported => {
+                let known = batteries.iter().map(Battery::id).collect::<Vec<_>>();
+                self.batteries()?
+                    .filter_map(|battery| battery.ok())
+                    .filter(|battery| !known.contains(&battery.id()))
+                    .collect::<Vec<_>>()
+            }
+            Err(e) => return Err(e),
+        };
+
+        summary.added.extend(added.iter().map(Battery::id));
+        batteries.extend(added);
+
+        Ok(summary)
+    }
+
+    /// Change battery charge control thresholds.
+    ///
+    /// Threshold passed as a `None` stays unchanged.
+    /// Thresholds should be in `0…100 %` range and `start` should be lower than `end`.\
+    /// Values are rounded to whole percents, as it is required by most of the devices.
+    ///
+    /// Changing thresholds usually requires root privileges,
+    /// [Error::kind](struct.Error.html#method.kind) will return
+    /// `ErrorKind::PermissionDenied` in that case.
+    ///
+    /// Passed `battery` is not updated, use the [Manager::refresh](#method.refresh)
+    /// method to fetch the new values.
+    ///
+    /// Currently supported on Linux only.
+    pub fn set_charge_thresholds(
+        &self,
+        battery: &Battery,
+        start: Option<Ratio>,
+        end: Option<Ratio>,
+    ) -> Result<()> {
+        let is_valid = |value: &Ratio| (0.0..=1.0).contains(&value.value);
+        if !start.iter().chain(end.iter()).all(is_valid) {
+            return Err(Error::invalid_input(
+                "Charge thresholds should be in 0…100 % range",
+            ));
+        }
+        if let (Some(start), Some(end)) = (start, end) {
+            if start >= end {
+                return Err(Error::invalid_input(
+                    "Charge start threshold should be lower than the end one",
+                ));
+            }
+        }
+
+        self.inner.set_charge_thresholds(battery, start, end)
+    }
+
+    /// Change battery charging behaviour.
+    ///
+    /// `behaviour` should be one of the
+    /// [Battery::available_charge_behaviours](struct.Battery.html#method.available_charge_behaviours),
+    /// otherwise the `ErrorKind::Unsupported` error is returned.
+    ///
+    /// Same as for the [Manager::set_charge_thresholds](#method.set_charge_thresholds) method,
+    /// root privileges are usually required and passed `battery` is not updated.
+    ///
+    /// Currently supported on Linux only.
+    pub fn set_charge_behaviour(
+        &self,
+        battery: &Battery,
+        behaviour: ChargeBehaviour,
+    ) -> Result<()> {
+        if !battery.available_charge_behaviours().contains(&behaviour) {
+            return Err(Error::unsupported(format!(
+                "Charge behaviour `{}` is not supported by the device",
+                behaviour
+            )));
+        }
+
+        self.inner.set_charge_behaviour(battery, behaviour)
+    }
+
+    /// Change battery charging algorithm.
+    ///
+    /// `charge_type` should be one of the
+    /// [Battery::available_charge_types](struct.Battery.html#method.available_charge_types),
+    /// otherwise the `ErrorKind::Unsupported` error is returned.
+    /// Devices reporting the active charge type only are not allowing to change it.
+    ///
+    /// Same as for the [Manager::set_charge_thresholds](#method.set_charge_thresholds) method,
+    /// root privileges are usually required and passed `battery` is not updated.
+    ///
+    /// Currently supported on Linux only.
+    pub fn set_charge_type(&self, battery: &Battery, charge_type: ChargeType) -> Result<()> {
+        if !battery.available_charge_types().contains(&charge_type) {
+            return Err(Error::unsupported(format!(
+                "Charge type `{}` is not supported by the device",
+                charge_type
+            )));
+        }
+
+        self.inner.set_charge_type(battery, charge_type)
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(crate) fn platform(&self) -> Arc<PlatformManager> {
+        self.inner.clone()
+    }
+}
+
+impl From<PlatformManager> for Manager {
+    fn from(inner: PlatformManager) -> Manager {
+        Manager {
+            inner: Arc::new(inner),
+        }
+    }
 }
 
 impl fmt::Debug for Manager {
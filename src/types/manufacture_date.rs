@@ -0,0 +1,63 @@
+use std::fmt;
+
+/// Battery manufacture date.
+///
+/// Devices might report the year only or the year and month only,
+/// so the rest of the components are optional.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ManufactureDate {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+impl ManufactureDate {
+    /// Returns `None` for the out of range components,
+    /// day without a month is ignored.
+    #[allow(unused)]
+    pub(crate) fn new(year: u16, month: Option<u8>, day: Option<u8>) -> Option<ManufactureDate> {
+        // Zeroes are reported by some firmwares for the unknown date
+        if year < 1970 {
+            return None;
+        }
+        let month = month.filter(|month| (1..=12).contains(month));
+        let day = match month {
+            Some(_) => day.filter(|day| (1..=31).contains(day)),
+            None => None,
+        };
+
+        Some(ManufactureDate { year, month, day })
+    }
+
+    /// Full year, for example, `2019`.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Month in `1…12` range.
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+
+    /// Day of month in `1…31` range.
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+}
+
+/// Formats date as `YYYY-MM-DD`, `YYYY-MM` or `YYYY`, depending on the available components.
+impl fmt::Display for ManufactureDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,59 @@
+mod adapter;
+#[cfg(all(target_os = "linux", feature = "upower"))]
+mod backend;
 mod battery;
+mod battery_id;
+mod builder;
+mod capacity_level;
+mod charge_behaviour;
+mod charge_thresholds;
+mod charge_type;
+mod composite;
+mod design_voltage_source;
+mod device_kind;
+mod estimator;
+mod health;
+mod identity;
 mod iterator;
 mod manager;
+mod manufacture_date;
+#[cfg(target_os = "linux")]
+mod monitor;
+mod power_flow;
+mod provenance;
+mod refresh_summary;
+mod sanitize_policy;
+mod scope;
+mod snapshot;
 mod state;
 mod technology;
 
+pub use self::adapter::{Adapter, AdapterType, UsbType};
+#[cfg(all(target_os = "linux", feature = "upower"))]
+pub use self::backend::Backend;
 pub use self::battery::Battery;
-pub use self::iterator::Batteries;
+pub use self::battery_id::BatteryId;
+pub use self::builder::ManagerBuilder;
+pub use self::capacity_level::CapacityLevel;
+pub use self::charge_behaviour::ChargeBehaviour;
+pub use self::charge_thresholds::ChargeThresholds;
+pub use self::charge_type::ChargeType;
+pub use self::composite::CompositeBattery;
+pub use self::design_voltage_source::DesignVoltageSource;
+pub use self::device_kind::DeviceKind;
+pub use self::estimator::Estimator;
+pub use self::health::Health;
+pub use self::identity::Identity;
+pub use self::iterator::{Adapters, Batteries};
 pub use self::manager::Manager;
+pub use self::manufacture_date::ManufactureDate;
+#[cfg(target_os = "linux")]
+pub use self::monitor::{BatteryEvent, Monitor, UEventSource};
+pub use self::power_flow::PowerFlow;
+pub use self::provenance::{DataProvenance, Provenance};
+pub use self::refresh_summary::RefreshSummary;
+pub use self::sanitize_policy::SanitizePolicy;
+pub use self::scope::Scope;
+pub use self::snapshot::BatterySnapshot;
 pub use self::state::State;
 pub use self::technology::Technology;
@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::platform::netlink::{NetlinkSocket, UEvent};
+use crate::platform::traits::BatteryManager;
+use crate::platform::Manager as PlatformManager;
+use crate::{Battery, Manager, Result};
+
+/// Source of the raw kernel uevent messages.
+///
+/// Implemented by the netlink socket used by the [Manager::monitor] method,
+/// but can be implemented by any other type to feed the recorded messages
+/// into the [Monitor::with_source] constructor.
+///
+/// [Manager::monitor]: struct.Manager.html#method.monitor
+/// [Monitor::with_source]: struct.Monitor.html#method.with_source
+pub trait UEventSource: Send {
+    /// Receive next message in the kernel `NETLINK_KOBJECT_UEVENT` format:
+    /// `{action}@{devpath}` header followed by the `KEY=VALUE` pairs, all separated by `\0`.
+    ///
+    /// Blocks until the message is available, `Ok(None)` means that source is exhausted.
+    fn recv(&mut self) -> Result<Option<Vec<u8>>>;
+}
+
+/// Battery change notification.
+///
+/// See [Monitor](struct.Monitor.html) for details.
+#[derive(Debug)]
+pub enum BatteryEvent {
+    /// Battery was plugged in.
+    Added(Battery),
+    /// Battery with this power supply name (ex. `BAT0`) was removed.
+    Removed(String),
+    /// Battery information was changed, contains an already refreshed battery.
+    Changed(Battery),
+}
+
+/// An iterator that yields [battery events] as they happen.
+///
+/// This struct is created by the [Manager::monitor](struct.Manager.html#method.monitor) method,
+/// `next()` call blocks until the next battery event.
+///
+/// Only batteries which would be returned by the [Manager::batteries](struct.Manager.html#method.batteries)
+/// are tracked, other power supplies are ignored.
+///
+/// [battery events]: enum.BatteryEvent.html
+pub struct Monitor {
+    manager: Arc<PlatformManager>,
+    source: Box<dyn UEventSource>,
+    // Removed power supplies can't be checked anymore
+    // if they were batteries, so known ones are tracked here
+    known: HashSet<String>,
+}
+
+impl Monitor {
+    /// Creates monitor which receives uevent messages from the `source`
+    /// instead of the kernel.
+    pub fn with_source<T>(manager: &Manager, source: T) -> Result<Monitor>
+    where
+        T: UEventSource + 'static,
+    {
+        let manager = manager.platform();
+        let known = manager.battery_names()?;
+
+        Ok(Monitor {
+            manager,
+            source: Box::new(source),
+            known,
+        })
+    }
+
+    pub(crate) fn new(manager: &Manager) -> Result<Monitor> {
+        Monitor::with_source(manager, NetlinkSocket::new()?)
+    }
+
+    fn handle(&mut self, event: UEvent) -> Result<Option<BatteryEvent>> {
+        match event.action.as_str() {
+            "add" | "change" => {
+                if !self.manager.is_battery(&event.name)? {
+                    return Ok(None);
+                }
+                let battery = Battery::from(self.manager.device(&event.name)?);
+                if self.known.insert(event.name) {
+                    Ok(Some(BatteryEvent::Added(battery)))
+                } else {
+                    Ok(Some(BatteryEvent::Changed(battery)))
+                }
+            }
+            "remove" if self.known.remove(&event.name) => {
+                Ok(Some(BatteryEvent::Removed(event.name)))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+impl Iterator for Monitor {
+    type Item = Result<BatteryEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let message = match self.source.recv() {
+                Ok(Some(message)) => message,
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            };
+            let event = match UEvent::parse(&message) {
+                Some(event) if event.subsystem == "power_supply" => event,
+                _ => continue,
+            };
+            match self.handle(event) {
+                Ok(Some(event)) => return Some(Ok(event)),
+                Ok(None) => continue,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Monitor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Monitor")
+            .field("manager", &self.manager)
+            .field("known", &self.known)
+            .finish()
+    }
+}
@@ -0,0 +1,50 @@
+use num_traits::identities::Zero;
+
+use crate::units::Power;
+use crate::State;
+
+/// Direction and amount of the power flowing in or out of the battery.
+///
+/// Unlike the [Battery::energy_rate](struct.Battery.html#method.energy_rate), which is always positive,
+/// it also takes into account the drivers reporting negative power or current values while discharging,
+/// so the discharging is detected even if the battery state is `Unknown`.
+/// Positive values are ambiguous, since most of the drivers are reporting unsigned ones,
+/// so on Linux the direction of the system battery with `Unknown` state
+/// is inferred from the [adapters](struct.Manager.html#method.adapters) instead:
+/// battery is charging if any of the AC or USB adapters is online and discharging otherwise.
+///
+/// Flow is `Idle` if the direction can't be inferred this way, for example,
+/// for the peripheral batteries or if there are no adapters reporting their status,
+/// even if the rate is not zero.
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub enum PowerFlow {
+    /// Battery is being charged with the given power.
+    Charging(Power),
+    /// Battery is being discharged with the given power.
+    Discharging(Power),
+    /// There is no power flow or its direction is unknown.
+    #[default]
+    Idle,
+}
+
+impl PowerFlow {
+    /// Flow for the battery `state` and positive `rate` value.
+    pub(crate) fn new(state: State, rate: Power) -> PowerFlow {
+        match state {
+            _ if rate.is_zero() => PowerFlow::Idle,
+            State::Charging => PowerFlow::Charging(rate),
+            State::Discharging => PowerFlow::Discharging(rate),
+            _ => PowerFlow::Idle,
+        }
+    }
+
+    /// Signed power value: positive while charging, negative while discharging
+    /// and zero if battery is idle.
+    pub fn power(&self) -> Power {
+        match self {
+            PowerFlow::Charging(power) => *power,
+            PowerFlow::Discharging(power) => -*power,
+            PowerFlow::Idle => Power::zero(),
+        }
+    }
+}
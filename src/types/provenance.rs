@@ -0,0 +1,117 @@
+use std::fmt;
+
+/// Source of the battery value: whether it was reported by the device
+/// or calculated by the crate from the other values.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+#[derive(Default)]
+pub enum Provenance {
+    /// Value is reported by the device as is.
+    #[default]
+    Measured,
+    /// Value is calculated from the charge value reported by the device
+    /// and the battery voltage,
+    /// see [Battery::design_voltage_source](struct.Battery.html#method.design_voltage_source).
+    DerivedFromCharge,
+    /// Value is calculated from the capacity percentage or the capacity level reported by the device.
+    DerivedFromCapacity,
+    /// Device does not report the value and there is no way to calculate it,
+    /// so the fallback value is used instead.
+    Defaulted,
+    /// Value is estimated from the values changes over time.
+    Estimated,
+}
+
+impl Provenance {
+    /// Provenance of the value calculated from the values with `self` and `other` provenances,
+    /// which is the least reliable one of them.
+    pub(crate) fn combine(self, other: Provenance) -> Provenance {
+        let rank = |provenance: &Provenance| match provenance {
+            Provenance::Measured => 0,
+            Provenance::DerivedFromCharge => 1,
+            Provenance::DerivedFromCapacity => 2,
+            Provenance::Estimated => 3,
+            Provenance::Defaulted => 4,
+        };
+
+        if rank(&other) > rank(&self) {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            Provenance::Measured => "measured",
+            Provenance::DerivedFromCharge => "derived-from-charge",
+            Provenance::DerivedFromCapacity => "derived-from-capacity",
+            Provenance::Defaulted => "defaulted",
+            Provenance::Estimated => "estimated",
+        };
+
+        write!(f, "{}", display)
+    }
+}
+
+/// Provenance of the battery values, which might be calculated by the crate
+/// if the device does not report them.
+///
+/// Returned by the [Battery::provenance](struct.Battery.html#method.provenance) method.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataProvenance {
+    pub(crate) state_of_charge: Provenance,
+    pub(crate) state_of_health: Provenance,
+    pub(crate) energy: Provenance,
+    pub(crate) energy_full: Provenance,
+    pub(crate) energy_full_design: Provenance,
+    pub(crate) energy_rate: Provenance,
+    pub(crate) voltage: Provenance,
+}
+
+impl DataProvenance {
+    /// Provenance of the [Battery::state_of_charge](struct.Battery.html#method.state_of_charge) value.
+    pub fn state_of_charge(&self) -> Provenance {
+        self.state_of_charge
+    }
+
+    /// Provenance of the [Battery::state_of_health](struct.Battery.html#method.state_of_health) value.
+    pub fn state_of_health(&self) -> Provenance {
+        self.state_of_health
+    }
+
+    /// Provenance of the [Battery::energy](struct.Battery.html#method.energy) value.
+    pub fn energy(&self) -> Provenance {
+        self.energy
+    }
+
+    /// Provenance of the [Battery::energy_full](struct.Battery.html#method.energy_full) value.
+    pub fn energy_full(&self) -> Provenance {
+        self.energy_full
+    }
+
+    /// Provenance of the [Battery::energy_full_design](struct.Battery.html#method.energy_full_design) value.
+    pub fn energy_full_design(&self) -> Provenance {
+        self.energy_full_design
+    }
+
+    /// Provenance of the [Battery::energy_rate](struct.Battery.html#method.energy_rate) value.
+    pub fn energy_rate(&self) -> Provenance {
+        self.energy_rate
+    }
+
+    /// Provenance of the [Battery::voltage](struct.Battery.html#method.voltage) value.
+    pub fn voltage(&self) -> Provenance {
+        self.voltage
+    }
+}
@@ -0,0 +1,32 @@
+use crate::BatteryId;
+
+/// Changes of the batteries list made by the
+/// [Manager::refresh_all](struct.Manager.html#method.refresh_all) method.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct RefreshSummary {
+    pub(crate) refreshed: Vec<BatteryId>,
+    pub(crate) added: Vec<BatteryId>,
+    pub(crate) removed: Vec<BatteryId>,
+}
+
+impl RefreshSummary {
+    /// Batteries which were in the list already and got refreshed.
+    pub fn refreshed(&self) -> &[BatteryId] {
+        &self.refreshed
+    }
+
+    /// Batteries which appeared since the last refresh and were appended to the list.
+    pub fn added(&self) -> &[BatteryId] {
+        &self.added
+    }
+
+    /// Batteries which are not available anymore and were removed from the list.
+    pub fn removed(&self) -> &[BatteryId] {
+        &self.removed
+    }
+
+    /// Returns `true` if any battery was added or removed.
+    pub fn is_changed(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty()
+    }
+}
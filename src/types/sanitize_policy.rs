@@ -0,0 +1,127 @@
+use crate::units::power::{milliwatt, watt};
+use crate::units::time::{day, hour};
+use crate::units::{Power, Time};
+
+/// Thresholds for the heuristics discarding the implausible values reported by the devices.
+///
+/// Default values are suitable for the most of notebooks,
+/// but might be too strict for the workstation laptops charging with more than 100 W
+/// or UPS-backed batteries lasting for days.
+///
+/// Passed to the [ManagerBuilder::sanitize_policy](struct.ManagerBuilder.html#method.sanitize_policy) method.
+///
+/// # Example
+///
+/// ```edition2018
+/// # use starship_battery::{Result, Manager, SanitizePolicy};
+/// # use starship_battery::units::power::watt;
+/// # use starship_battery::units::Power;
+/// # fn main() -> Result<()> {
+/// let policy = SanitizePolicy::default()
+///     .max_energy_rate(Some(Power::new::<watt>(240.0)))
+///     .max_time_to_empty(None);
+/// let manager = Manager::builder().sanitize_policy(policy).build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct SanitizePolicy {
+    pub(crate) max_energy_rate: Option<Power>,
+    pub(crate) min_power: Power,
+    pub(crate) ignore_acpi_ones: bool,
+    pub(crate) max_time_to_full: Option<Time>,
+    pub(crate) max_time_to_empty: Option<Time>,
+}
+
+impl SanitizePolicy {
+    /// Policy which is not discarding any values.
+    pub fn none() -> SanitizePolicy {
+        SanitizePolicy {
+            max_energy_rate: None,
+            min_power: Power::new::<watt>(0.0),
+            ignore_acpi_ones: false,
+            max_time_to_full: None,
+            max_time_to_empty: None,
+        }
+    }
+
+    /// Energy rate above this value is considered bogus and reported as zero,
+    /// `None` disables the check.
+    ///
+    /// Default is `100 W`.
+    pub fn max_energy_rate(mut self, value: Option<Power>) -> Self {
+        self.max_energy_rate = value;
+        self
+    }
+
+    /// Power reported by the device below this value is ignored
+    /// and the energy rate is calculated from the current instead.
+    ///
+    /// Default is `10 mW`.
+    pub fn min_power(mut self, value: Power) -> Self {
+        self.min_power = value;
+        self
+    }
+
+    /// ACPI reports the `0xFFFF` value as an energy rate, when it is unable to calculate it.
+    /// If enabled, such rate is reported as zero.
+    ///
+    /// Enabled by default.
+    pub fn ignore_acpi_ones(mut self, value: bool) -> Self {
+        self.ignore_acpi_ones = value;
+        self
+    }
+
+    /// Calculated time to full above this value is reported as `None`,
+    /// `None` disables the check.
+    ///
+    /// Default is `10 hours`.
+    pub fn max_time_to_full(mut self, value: Option<Time>) -> Self {
+        self.max_time_to_full = value;
+        self
+    }
+
+    /// Calculated time to empty above this value is reported as `None`,
+    /// `None` disables the check.
+    ///
+    /// Default is `10 days`.
+    pub fn max_time_to_empty(mut self, value: Option<Time>) -> Self {
+        self.max_time_to_empty = value;
+        self
+    }
+
+    /// Applies the energy rate checks, returning zero for the discarded values.
+    pub(crate) fn energy_rate(&self, value: Power) -> Power {
+        let zero = Power::new::<watt>(0.0);
+        match self.max_energy_rate {
+            Some(max) if value > max => return zero,
+            _ => {}
+        }
+        // ACPI gives out the special 'Ones' (Constant Ones Object) value for rate
+        // when it's unable to calculate the true rate. We should set the rate zero,
+        // and wait for the BIOS to stabilise.
+        // Source: upower
+        //
+        // It come as an `0xffff` originally, but we are operating with `Power` now,
+        // so this `Ones` value is recalculated a little.
+        // TODO: There might be a chance that we had lost a precision during the conversion
+        // from the microwatts into default watts, so this should be fixed
+        if self.ignore_acpi_ones && (value.get::<watt>() - 65535.0).abs() < f32::EPSILON {
+            return zero;
+        }
+
+        value
+    }
+}
+
+impl Default for SanitizePolicy {
+    fn default() -> Self {
+        SanitizePolicy {
+            max_energy_rate: Some(Power::new::<watt>(100.0)),
+            min_power: Power::new::<milliwatt>(10.0),
+            ignore_acpi_ones: true,
+            max_time_to_full: Some(Time::new::<hour>(10.0)),
+            max_time_to_empty: Some(Time::new::<day>(10.0)),
+        }
+    }
+}
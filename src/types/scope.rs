@@ -0,0 +1,52 @@
+use std::fmt;
+use std::str;
+
+use crate::Error;
+
+/// Power topology of the battery.
+///
+/// A power supply which does not report its scope is assumed to have the `System` scope.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+#[derive(Default)]
+pub enum Scope {
+    /// Powers the whole system, for example, notebook battery.
+    #[default]
+    System,
+    /// Powers a specific device or tree of devices, for example, wireless mouse.
+    Device,
+    /// Unknown power topology.
+    Unknown,
+}
+
+impl str::FromStr for Scope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let scope = match s {
+            _ if s.eq_ignore_ascii_case("System") => Scope::System,
+            _ if s.eq_ignore_ascii_case("Device") => Scope::Device,
+            _ => Scope::Unknown,
+        };
+
+        Ok(scope)
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match self {
+            Scope::System => "system",
+            Scope::Device => "device",
+            Scope::Unknown => "unknown",
+        };
+
+        write!(f, "{}", display)
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::BTreeMap;
+use std::time::SystemTime;
+
+use crate::units::electric_charge::ampere_hour;
+use crate::units::electric_current::ampere;
+use crate::units::electric_potential::volt;
+use crate::units::energy::watt_hour;
+use crate::units::power::watt;
+use crate::units::ratio::percent;
+use crate::units::thermodynamic_temperature::degree_celsius;
+use crate::units::time::second;
+use crate::{
+    Battery, BatteryId, CapacityLevel, ChargeBehaviour, ChargeType, DataProvenance,
+    DesignVoltageSource, DeviceKind, Health, ManufactureDate, Scope, State, Technology,
+};
+
+/// Plain-data copy of the battery information at the moment of the snapshot.
+///
+/// Unlike the [Battery], it is not bound to the platform device,
+/// so it can be cloned, sent across threads and (with the `serde` feature)
+/// serialized, for example, to pass it over IPC or to store it.
+///
+/// Values are stored as the plain numbers in the units stated in the field names,
+/// values which are not reported by the device are `None`.
+///
+/// Created by the [Battery::snapshot] method,
+/// or by the [Battery::snapshot_with_raw_properties] one to include the raw device properties,
+/// which takes reading all of them.
+///
+/// # Example
+///
+/// ```edition2018
+/// # use starship_battery::{Result, Manager};
+/// # fn main() -> Result<()> {
+/// for battery in Manager::new()?.batteries()? {
+///     let snapshot = battery?.snapshot();
+///     println!("{}: {:.1} %", snapshot.id, snapshot.percentage);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Battery]: struct.Battery.html
+/// [Battery::snapshot]: struct.Battery.html#method.snapshot
+/// [Battery::snapshot_with_raw_properties]: struct.Battery.html#method.snapshot_with_raw_properties
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatterySnapshot {
+    /// Time when the snapshot was taken.
+    pub timestamp: SystemTime,
+
+    // Static information
+    /// See [Battery::id](struct.Battery.html#method.id).
+    pub id: BatteryId,
+    /// See [Battery::vendor](struct.Battery.html#method.vendor).
+    pub vendor: Option<String>,
+    /// See [Battery::model](struct.Battery.html#method.model).
+    pub model: Option<String>,
+    /// See [Battery::serial_number](struct.Battery.html#method.serial_number).
+    pub serial_number: Option<String>,
+    /// See [Battery::manufacture_date](struct.Battery.html#method.manufacture_date).
+    pub manufacture_date: Option<ManufactureDate>,
+    /// See [Identity::battery_type](struct.Identity.html#method.battery_type).
+    pub battery_type: Option<String>,
+    /// See [Battery::technology](struct.Battery.html#method.technology).
+    pub technology: Technology,
+    /// See [Battery::scope](struct.Battery.html#method.scope).
+    pub scope: Scope,
+    /// See [Battery::device_kind](struct.Battery.html#method.device_kind).
+    pub device_kind: Option<DeviceKind>,
+
+    // Common information
+    /// See [Battery::state](struct.Battery.html#method.state).
+    pub state: State,
+    /// See [Battery::health](struct.Battery.html#method.health).
+    pub health: Option<Health>,
+    /// State of charge, see [Battery::state_of_charge](struct.Battery.html#method.state_of_charge).
+    pub percentage: f32,
+    /// See [Battery::try_state_of_health](struct.Battery.html#method.try_state_of_health).
+    pub state_of_health_percentage: Option<f32>,
+    /// See [Battery::capacity_level](struct.Battery.html#method.capacity_level).
+    pub capacity_level: Option<CapacityLevel>,
+    /// See [Battery::capacity_error_margin](struct.Battery.html#method.capacity_error_margin).
+    pub capacity_error_margin_percentage: Option<f32>,
+    /// See [Battery::temperature](struct.Battery.html#method.temperature).
+    pub temperature_celsius: Option<f32>,
+    /// See [Battery::cycle_count](struct.Battery.html#method.cycle_count).
+    pub cycle_count: Option<u32>,
+
+    // Charge control
+    /// See [ChargeThresholds::start](struct.ChargeThresholds.html#method.start).
+    pub charge_start_threshold_percentage: Option<f32>,
+    /// See [ChargeThresholds::end](struct.ChargeThresholds.html#method.end).
+    pub charge_end_threshold_percentage: Option<f32>,
+    /// See [Battery::charge_behaviour](struct.Battery.html#method.charge_behaviour).
+    pub charge_behaviour: Option<ChargeBehaviour>,
+    /// See [Battery::available_charge_behaviours](struct.Battery.html#method.available_charge_behaviours).
+    pub available_charge_behaviours: Vec<ChargeBehaviour>,
+    /// See [Battery::charge_type](struct.Battery.html#method.charge_type).
+    pub charge_type: Option<ChargeType>,
+    /// See [Battery::available_charge_types](struct.Battery.html#method.available_charge_types).
+    pub available_charge_types: Vec<ChargeType>,
+
+    // Energy stats
+    /// See [Battery::try_energy](struct.Battery.html#method.try_energy).
+    pub energy_wh: Option<f32>,
+    /// See [Battery::try_energy_full](struct.Battery.html#method.try_energy_full).
+    pub energy_full_wh: Option<f32>,
+    /// See [Battery::try_energy_full_design](struct.Battery.html#method.try_energy_full_design).
+    pub energy_full_design_wh: Option<f32>,
+    /// See [Battery::try_energy_rate](struct.Battery.html#method.try_energy_rate).
+    pub energy_rate_w: Option<f32>,
+    /// Signed power flow, positive while charging,
+    /// see [PowerFlow::power](enum.PowerFlow.html#method.power).
+    pub power_flow_w: f32,
+    /// See [Battery::try_voltage](struct.Battery.html#method.try_voltage).
+    pub voltage_v: Option<f32>,
+    /// See [Battery::provenance](struct.Battery.html#method.provenance).
+    pub provenance: DataProvenance,
+
+    // Native charge stats
+    /// See [Battery::current](struct.Battery.html#method.current).
+    pub current_a: Option<f32>,
+    /// See [Battery::charge](struct.Battery.html#method.charge).
+    pub charge_ah: Option<f32>,
+    /// See [Battery::charge_full](struct.Battery.html#method.charge_full).
+    pub charge_full_ah: Option<f32>,
+    /// See [Battery::charge_full_design](struct.Battery.html#method.charge_full_design).
+    pub charge_full_design_ah: Option<f32>,
+    /// See [Battery::charge_counter](struct.Battery.html#method.charge_counter).
+    pub charge_counter_ah: Option<f32>,
+
+    // Voltage stats
+    /// See [Battery::voltage_min_design](struct.Battery.html#method.voltage_min_design).
+    pub voltage_min_design_v: Option<f32>,
+    /// See [Battery::voltage_max_design](struct.Battery.html#method.voltage_max_design).
+    pub voltage_max_design_v: Option<f32>,
+    /// See [Battery::voltage_ocv](struct.Battery.html#method.voltage_ocv).
+    pub voltage_ocv_v: Option<f32>,
+    /// See [Battery::voltage_avg](struct.Battery.html#method.voltage_avg).
+    pub voltage_avg_v: Option<f32>,
+    /// See [Battery::design_voltage_source](struct.Battery.html#method.design_voltage_source).
+    pub design_voltage_source: Option<DesignVoltageSource>,
+
+    // Charge stats
+    /// See [Battery::time_to_full](struct.Battery.html#method.time_to_full).
+    pub time_to_full_secs: Option<f32>,
+    /// See [Battery::time_to_empty](struct.Battery.html#method.time_to_empty).
+    pub time_to_empty_secs: Option<f32>,
+
+    /// See [Battery::raw_properties](struct.Battery.html#method.raw_properties),
+    /// `None` unless the snapshot is created with the
+    /// [Battery::snapshot_with_raw_properties](struct.Battery.html#method.snapshot_with_raw_properties) method.
+    pub raw_properties: Option<BTreeMap<String, String>>,
+}
+
+impl BatterySnapshot {
+    pub(crate) fn new(
+        battery: &Battery,
+        timestamp: SystemTime,
+        with_raw_properties: bool,
+    ) -> BatterySnapshot {
+        let thresholds = battery.charge_thresholds().unwrap_or_default();
+        let identity = battery.identity();
+
+        BatterySnapshot {
+            timestamp,
+
+            id: battery.id(),
+            vendor: battery.vendor().map(ToString::to_string),
+            model: battery.model().map(ToString::to_string),
+            serial_number: battery.serial_number().map(ToString::to_string),
+            manufacture_date: battery.manufacture_date(),
+            battery_type: identity.battery_type().map(ToString::to_string),
+            technology: battery.technology(),
+            scope: battery.scope(),
+            device_kind: battery.device_kind(),
+
+            state: battery.state(),
+            health: battery.health(),
+            percentage: battery.state_of_charge().get::<percent>(),
+            state_of_health_percentage: battery
+                .try_state_of_health()
+                .map(|value| value.get::<percent>()),
+            capacity_level: battery.capacity_level(),
+            capacity_error_margin_percentage: battery
+                .capacity_error_margin()
+                .map(|value| value.get::<percent>()),
+            temperature_celsius: battery
+                .temperature()
+                .map(|value| value.get::<degree_celsius>()),
+            cycle_count: battery.cycle_count(),
+
+            charge_start_threshold_percentage: thresholds
+                .start()
+                .map(|value| value.get::<percent>()),
+            charge_end_threshold_percentage: thresholds.end().map(|value| value.get::<percent>()),
+            charge_behaviour: battery.charge_behaviour(),
+            available_charge_behaviours: battery.available_charge_behaviours().to_vec(),
+            charge_type: battery.charge_type(),
+            available_charge_types: battery.available_charge_types().to_vec(),
+
+            energy_wh: battery.try_energy().map(|value| value.get::<watt_hour>()),
+            energy_full_wh: battery
+                .try_energy_full()
+                .map(|value| value.get::<watt_hour>()),
+            energy_full_design_wh: battery
+                .try_energy_full_design()
+                .map(|value| value.get::<watt_hour>()),
+            energy_rate_w: battery.try_energy_rate().map(|value| value.get::<watt>()),
+            power_flow_w: battery.power_flow().power().get::<watt>(),
+            voltage_v: battery.try_voltage().map(|value| value.get::<volt>()),
+            provenance: battery.provenance(),
+
+            current_a: battery.current().map(|value| value.get::<ampere>()),
+            charge_ah: battery.charge().map(|value| value.get::<ampere_hour>()),
+            charge_full_ah: battery
+                .charge_full()
+                .map(|value| value.get::<ampere_hour>()),
+            charge_full_design_ah: battery
+                .charge_full_design()
+                .map(|value| value.get::<ampere_hour>()),
+            charge_counter_ah: battery
+                .charge_counter()
+                .map(|value| value.get::<ampere_hour>()),
+
+            voltage_min_design_v: battery
+                .voltage_min_design()
+                .map(|value| value.get::<volt>()),
+            voltage_max_design_v: battery
+                .voltage_max_design()
+                .map(|value| value.get::<volt>()),
+            voltage_ocv_v: battery.voltage_ocv().map(|value| value.get::<volt>()),
+            voltage_avg_v: battery.voltage_avg().map(|value| value.get::<volt>()),
+            design_voltage_source: battery.design_voltage_source(),
+
+            time_to_full_secs: battery.time_to_full().map(|value| value.get::<second>()),
+            time_to_empty_secs: battery.time_to_empty().map(|value| value.get::<second>()),
+
+            // Reading raw properties requires to read all the device attributes
+            raw_properties: with_raw_properties.then(|| battery.raw_properties().clone()),
+        }
+    }
+}
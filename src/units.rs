@@ -115,6 +115,7 @@ macro_rules! impl_into_quantity {
 
 impl_into_quantity!(electric_charge::milliampere_hour, ElectricCharge);
 impl_into_quantity!(electric_charge::microampere_hour, ElectricCharge);
+impl_into_quantity!(energy::watt_hour, Energy);
 impl_into_quantity!(energy::milliwatt_hour, Energy);
 impl_into_quantity!(energy::microwatt_hour, Energy);
 impl_into_quantity!(electric_current::milliampere, ElectricCurrent);
@@ -151,6 +152,13 @@ macro_rules! microampere_hour {
     };
 }
 
+/// Create `Energy` quantity with `watt_hour` unit
+macro_rules! watt_hour {
+    ($value:expr) => {
+        unit!($crate::units::energy::watt_hour, $value)
+    };
+}
+
 /// Create `Energy` quantity with `milliwatt_hour` unit
 macro_rules! milliwatt_hour {
     ($value:expr) => {